@@ -43,3 +43,12 @@ fn new_seed(b: &mut Bencher) {
         let _ = Seed::new(&m, "");
     });
 }
+
+#[bench]
+fn index_of(b: &mut Bencher) {
+    let wordlist = Language::English.wordlist();
+
+    b.iter(|| {
+        let _ = wordlist.index_of("vibrant");
+    });
+}
@@ -0,0 +1,79 @@
+//! Generates the word lists as static arrays, so they don't have to be split and indexed at
+//! runtime
+//!
+//! For each BIP0039 list in `src/langs` this writes the words in list order, the indices of the
+//! words in sorted order for prefix search, and an open addressing hash table of the indices for
+//! looking words up. The SLIP-0039 list is sorted to begin with, and the 1626 words of pre-2.0
+//! Electrum seeds are few enough to search in list order.
+//!
+//! Only the lists of enabled features are read.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// (file in src/langs, name of the static, feature, number of words)
+const LISTS: &[(&str, &str, Option<&str>, usize)] = &[
+    ("english.txt", "ENGLISH", None, 2048),
+    ("chinese_simplified.txt", "CHINESE_SIMPLIFIED", Some("chinese-simplified"), 2048),
+    ("chinese_traditional.txt", "CHINESE_TRADITIONAL", Some("chinese-traditional"), 2048),
+    ("french.txt", "FRENCH", Some("french"), 2048),
+    ("italian.txt", "ITALIAN", Some("italian"), 2048),
+    ("japanese.txt", "JAPANESE", Some("japanese"), 2048),
+    ("korean.txt", "KOREAN", Some("korean"), 2048),
+    ("spanish.txt", "SPANISH", Some("spanish"), 2048),
+    ("czech.txt", "CZECH", Some("czech"), 2048),
+    ("portuguese.txt", "PORTUGUESE", Some("portuguese"), 2048),
+    ("slip39.txt", "SLIP39", Some("slip39"), 1024),
+    ("electrum_v1.txt", "ELECTRUM_V1", Some("electrum"), 1626),
+];
+
+// `MAX_WORD_BYTES`, `hash_table` and `hash_word`, shared with src/language.rs
+include!("src/hash.rs");
+
+fn main() {
+    let mut code = String::new();
+
+    println!("cargo:rerun-if-changed=src/hash.rs");
+
+    for &(file, name, feature, len) in LISTS {
+        if let Some(feature) = feature {
+            if env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"))).is_none() {
+                continue;
+            }
+        }
+
+        let path = Path::new("src/langs").join(file);
+
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let text = fs::read_to_string(&path).unwrap_or_else(|error| panic!("can't read {}: {}", path.display(), error));
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        assert_eq!(words.len(), len, "{} has {} words", file, words.len());
+        assert!(words.iter().all(|word| word.len() <= MAX_WORD_BYTES), "{} has words that are too long", file);
+
+        let cfg = match feature {
+            Some(feature) => format!("#[cfg(feature = \"{}\")]\n", feature),
+            None => String::new(),
+        };
+
+        write!(code, "{}pub static {}: [&str; {}] = {:?};\n\n", cfg, name, len, words).unwrap();
+
+        if len == 2048 {
+            let mut sorted: Vec<u16> = (0..len as u16).collect();
+
+            sorted.sort_by_key(|&i| words[i as usize]);
+
+            write!(code, "{}pub static {}_SORTED: [u16; {}] = {:?};\n\n", cfg, name, len, sorted).unwrap();
+            write!(code, "{}pub static {}_TABLE: [u16; {}] = {:?};\n\n", cfg, name, 2 * len, hash_table(&words)).unwrap();
+        } else if name == "SLIP39" {
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]), "{} isn't sorted", file);
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("wordlists.rs");
+
+    fs::write(out, code).unwrap();
+}
@@ -0,0 +1,823 @@
+//! [BIP0032][bip32-standard] hierarchical deterministic key derivation from a [`Seed`][Seed]
+//!
+//! Only available with the `bip32` feature, which is off by default.
+//!
+//! ```rust
+//! use bip39::{Mnemonic, Language, Seed};
+//! use bip39::bip32::{DerivationPath, ExtendedPrivKey, Network};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! let seed = Seed::new(&mnemonic, "");
+//!
+//! let master = ExtendedPrivKey::new_master(&seed, Network::Mainnet).unwrap();
+//! let path: DerivationPath = "m/44'/0'/0'/0/0".parse().unwrap();
+//! let key = master.derive_path(&path).unwrap();
+//!
+//! // share the account level public key with a watch-only wallet
+//! let account: DerivationPath = "m/44'/0'/0'".parse().unwrap();
+//! let xpub = master.derive_path(&account).unwrap().to_extended_pub_key();
+//!
+//! println!("{} {}", key, xpub);
+//! ```
+//!
+//! [bip32-standard]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//! [Seed]: ../seed/struct.Seed.html
+//!
+
+use crypto::{hash160, hmac_sha512};
+use error::ErrorKind;
+use error::Error;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{FieldBytes, ProjectivePoint, PublicKey, Scalar};
+use seed::Seed;
+use std::fmt;
+use std::str::FromStr;
+use util::{base58check_decode, base58check_encode};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const HARDENED_BIT: u32 = 1 << 31;
+const MASTER_KEY: &[u8] = b"Bitcoin seed";
+const SERIALIZED_LEN: usize = 78;
+
+const VERSION_XPRV: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+const VERSION_XPUB: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+const VERSION_TPRV: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+const VERSION_TPUB: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+
+/// The network an extended key is serialized for
+///
+/// This only determines the version bytes, and therefore the `xprv`/`xpub` or `tprv`/`tpub`
+/// prefix of the serialized key. Derivation is the same on every network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Testnet,
+}
+
+impl Network {
+    fn private_version(&self) -> [u8; 4] {
+        match *self {
+            Network::Mainnet => VERSION_XPRV,
+            Network::Testnet => VERSION_TPRV,
+        }
+    }
+
+    fn public_version(&self) -> [u8; 4] {
+        match *self {
+            Network::Mainnet => VERSION_XPUB,
+            Network::Testnet => VERSION_TPUB,
+        }
+    }
+}
+
+/// The index of a key in its parent's list of children
+///
+/// Both variants hold the index without the hardened bit, so `ChildNumber::Hardened(0)` is the
+/// child written as `0'` in a [`DerivationPath`][DerivationPath]. Indexes must be below 2<sup>31</sup>.
+///
+/// [DerivationPath]: ./struct.DerivationPath.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChildNumber {
+    Normal(u32),
+    Hardened(u32),
+}
+
+impl ChildNumber {
+    /// Get the index of the child, without the hardened bit
+    pub fn index(&self) -> u32 {
+        match *self {
+            ChildNumber::Normal(index) | ChildNumber::Hardened(index) => index,
+        }
+    }
+
+    /// Whether this is a hardened child, which can only be derived from a private key
+    pub fn is_hardened(&self) -> bool {
+        match *self {
+            ChildNumber::Normal(_) => false,
+            ChildNumber::Hardened(_) => true,
+        }
+    }
+
+    /// Get the index as serialized in an extended key, with the hardened bit set if needed
+    pub(crate) fn to_raw(self) -> Result<u32, Error> {
+        let index = self.index();
+
+        if index >= HARDENED_BIT {
+            Err(ErrorKind::InvalidChildNumber(index))?;
+        }
+
+        Ok(match self {
+            ChildNumber::Normal(_) => index,
+            ChildNumber::Hardened(_) => index | HARDENED_BIT,
+        })
+    }
+}
+
+impl From<u32> for ChildNumber {
+    /// Create a `ChildNumber` from its serialized form, where the hardened bit marks hardened children
+    fn from(raw: u32) -> ChildNumber {
+        if raw & HARDENED_BIT == 0 {
+            ChildNumber::Normal(raw)
+        } else {
+            ChildNumber::Hardened(raw ^ HARDENED_BIT)
+        }
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChildNumber::Normal(index) => write!(f, "{}", index),
+            ChildNumber::Hardened(index) => write!(f, "{}'", index),
+        }
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    /// Parse a single path component such as `44'`, `44h` or `0`
+    fn from_str(s: &str) -> Result<ChildNumber, Error> {
+        let invalid = || ErrorKind::InvalidDerivationPath(s.to_string());
+
+        let (digits, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
+            Some(digits) => (digits, true),
+            None => (s, false),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            Err(invalid())?;
+        }
+
+        let index: u32 = digits.parse().map_err(|_| invalid())?;
+
+        if index >= HARDENED_BIT {
+            Err(invalid())?;
+        }
+
+        Ok(if hardened { ChildNumber::Hardened(index) } else { ChildNumber::Normal(index) })
+    }
+}
+
+/// A list of [`ChildNumber`][ChildNumber]s leading from a master key to one of its descendants
+///
+/// Paths are written in the usual `m/44'/0'/0'/0/0` notation, hardened children may also be
+/// marked with `h` or `H` instead of an apostrophe.
+///
+/// # Example
+///
+/// ```
+/// use bip39::bip32::{ChildNumber, DerivationPath};
+///
+/// let path: DerivationPath = "m/44'/0'/0'/0/0".parse().unwrap();
+///
+/// assert_eq!(path.as_ref()[0], ChildNumber::Hardened(44));
+/// assert_eq!(path.to_string(), "m/44'/0'/0'/0/0");
+/// ```
+///
+/// [ChildNumber]: ./enum.ChildNumber.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DerivationPath {
+    path: Vec<ChildNumber>,
+}
+
+impl DerivationPath {
+    /// Get a new path that extends this one with `child`
+    pub fn child(&self, child: ChildNumber) -> DerivationPath {
+        let mut path = self.path.clone();
+
+        path.push(child);
+
+        DerivationPath {
+            path,
+        }
+    }
+
+    /// Iterate over the [`ChildNumber`][ChildNumber]s of the path, from the master key down
+    ///
+    /// [ChildNumber]: ./enum.ChildNumber.html
+    pub fn iter(&self) -> ::std::slice::Iter<'_, ChildNumber> {
+        self.path.iter()
+    }
+}
+
+impl AsRef<[ChildNumber]> for DerivationPath {
+    fn as_ref(&self) -> &[ChildNumber] {
+        &self.path
+    }
+}
+
+impl From<Vec<ChildNumber>> for DerivationPath {
+    fn from(path: Vec<ChildNumber>) -> DerivationPath {
+        DerivationPath {
+            path,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DerivationPath {
+    type Item = &'a ChildNumber;
+    type IntoIter = ::std::slice::Iter<'a, ChildNumber>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("m")?;
+
+        for child in &self.path {
+            write!(f, "/{}", child)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<DerivationPath, Error> {
+        let mut parts = s.split('/');
+
+        if parts.next() != Some("m") {
+            Err(ErrorKind::InvalidDerivationPath(s.to_string()))?;
+        }
+
+        let path = parts.map(|part| part.parse().map_err(|_| ErrorKind::InvalidDerivationPath(s.to_string())))
+                        .collect::<Result<_, _>>()?;
+
+        Ok(DerivationPath {
+            path,
+        })
+    }
+}
+
+/// An extended private key, from which both private and public child keys can be derived
+///
+/// Create the master key of a wallet with [`ExtendedPrivKey::new_master()`][ExtendedPrivKey::new_master()],
+/// then derive keys from it with [`ExtendedPrivKey::derive_path()`][ExtendedPrivKey::derive_path()].
+///
+/// Formatting an `ExtendedPrivKey` with `{}` gives its Base58Check serialization (`xprv...`
+/// or `tprv...`), which can be parsed back with `str::parse()`.
+///
+/// With the `zeroize` feature (enabled by default) the private key and chain code are wiped from
+/// memory when dropped.
+///
+/// [ExtendedPrivKey::new_master()]: ./struct.ExtendedPrivKey.html#method.new_master
+/// [ExtendedPrivKey::derive_path()]: ./struct.ExtendedPrivKey.html#method.derive_path
+#[derive(Clone)]
+pub struct ExtendedPrivKey {
+    network: Network,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: [u8; 32],
+    private_key: [u8; 32],
+}
+
+impl ExtendedPrivKey {
+    /// Create the master key of a wallet from a [`Seed`][Seed]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    /// use bip39::bip32::{ExtendedPrivKey, Network};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "TREZOR");
+    ///
+    /// let master = ExtendedPrivKey::new_master(&seed, Network::Mainnet).unwrap();
+    ///
+    /// assert_eq!(master.to_string(), "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF");
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn new_master(seed: &Seed, network: Network) -> Result<ExtendedPrivKey, Error> {
+        Self::from_seed_bytes(seed.as_bytes(), network)
+    }
+
+    /// Create the master key of a wallet from raw seed bytes
+    ///
+    /// This is meant for seeds that did not come from a BIP0039 [`Mnemonic`][Mnemonic], use
+    /// [`ExtendedPrivKey::new_master()`][ExtendedPrivKey::new_master()] otherwise. BIP0032 allows
+    /// seeds between 16 and 64 bytes long, any other length returns an `Error` of kind
+    /// `ErrorKind::InvalidSeedLength`.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [ExtendedPrivKey::new_master()]: ./struct.ExtendedPrivKey.html#method.new_master
+    pub fn from_seed_bytes(seed: &[u8], network: Network) -> Result<ExtendedPrivKey, Error> {
+        if seed.len() < 16 || seed.len() > 64 {
+            Err(ErrorKind::InvalidSeedLength(seed.len()))?;
+        }
+
+        #[allow(unused_mut)]
+        let mut output = hmac_sha512(MASTER_KEY, &[seed]);
+        let key = Self::from_hmac_output(&output, network, 0, [0; 4], ChildNumber::Normal(0), None);
+
+        #[cfg(feature = "zeroize")]
+        output.zeroize();
+
+        key
+    }
+
+    /// Build a key from the output of the BIP0032 HMAC, where the left half is either the private
+    /// key itself (`parent` is `None`) or the tweak to add to the `parent` key.
+    pub(crate) fn from_hmac_output(
+        output: &[u8; 64],
+        network: Network,
+        depth: u8,
+        parent_fingerprint: [u8; 4],
+        child_number: ChildNumber,
+        parent: Option<&Scalar>,
+    ) -> Result<ExtendedPrivKey, Error> {
+        let tweak = scalar_from_bytes(&output[..32]).ok_or(ErrorKind::InvalidDerivedKey)?;
+
+        let key = match parent {
+            Some(parent) => tweak + parent,
+            None => tweak,
+        };
+
+        if bool::from(key.is_zero()) {
+            Err(ErrorKind::InvalidDerivedKey)?;
+        }
+
+        let mut chain_code = [0u8; 32];
+        let mut private_key = [0u8; 32];
+
+        chain_code.copy_from_slice(&output[32..]);
+        private_key.copy_from_slice(&key.to_bytes());
+
+        Ok(ExtendedPrivKey {
+            network,
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Derive the child key at `child`
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidDerivedKey` in the astronomically unlikely case
+    /// that the child key is invalid, in which case BIP0032 says to proceed with the next index.
+    pub fn derive_child(&self, child: ChildNumber) -> Result<ExtendedPrivKey, Error> {
+        let raw = child.to_raw()?.to_be_bytes();
+        let depth = self.depth.checked_add(1).ok_or(ErrorKind::InvalidDerivedKey)?;
+        let parent = self.scalar();
+
+        #[allow(unused_mut)]
+        let mut output = if child.is_hardened() {
+            hmac_sha512(&self.chain_code, &[&[0], &self.private_key, &raw])
+        } else {
+            hmac_sha512(&self.chain_code, &[&self.public_key(), &raw])
+        };
+
+        let key = Self::from_hmac_output(&output, self.network, depth, self.fingerprint(), child, Some(&parent));
+
+        #[cfg(feature = "zeroize")]
+        output.zeroize();
+
+        key
+    }
+
+    /// Derive the descendant at `path`, relative to this key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<ExtendedPrivKey, Error> {
+        let mut key = self.clone();
+
+        for child in path {
+            key = key.derive_child(*child)?;
+        }
+
+        Ok(key)
+    }
+
+    /// Get the [`ExtendedPubKey`][ExtendedPubKey] for this key, which can derive the same normal
+    /// (but not hardened) children without access to any private key
+    ///
+    /// [ExtendedPubKey]: ./struct.ExtendedPubKey.html
+    pub fn to_extended_pub_key(&self) -> ExtendedPubKey {
+        ExtendedPubKey {
+            network: self.network,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            public_key: self.public_key(),
+        }
+    }
+
+    /// Get the raw 32 byte private key
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.private_key
+    }
+
+    /// Get the compressed 33 byte public key
+    pub fn public_key(&self) -> [u8; 33] {
+        public_key_bytes(&(ProjectivePoint::GENERATOR * self.scalar()))
+    }
+
+    /// Get the chain code
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Get the [`Network`][Network] the key is serialized for
+    ///
+    /// [Network]: ./enum.Network.html
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Get the number of derivation steps from the master key, which is at depth 0
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Get the fingerprint of the parent key, or zeroes for a master key
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// Get the [`ChildNumber`][ChildNumber] this key was derived with
+    ///
+    /// [ChildNumber]: ./enum.ChildNumber.html
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Get the first 4 bytes of the key identifier, as used for `parent_fingerprint` by children
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.to_extended_pub_key().fingerprint()
+    }
+
+    fn scalar(&self) -> Scalar {
+        scalar_from_bytes(&self.private_key).expect("private key is always valid")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ExtendedPrivKey {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl fmt::Display for ExtendedPrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut key_data = [0u8; 33];
+
+        key_data[1..].copy_from_slice(&self.private_key);
+
+        #[allow(unused_mut)]
+        let mut serialized = serialize(
+            self.network.private_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &key_data,
+        );
+
+        #[allow(unused_mut)]
+        let mut encoded = base58check_encode(&serialized);
+        let result = f.write_str(&encoded);
+
+        #[cfg(feature = "zeroize")]
+        {
+            key_data.zeroize();
+            serialized.zeroize();
+            encoded.zeroize();
+        }
+
+        result
+    }
+}
+
+impl fmt::Debug for ExtendedPrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtendedPrivKey")
+         .field("network", &self.network)
+         .field("depth", &self.depth)
+         .field("parent_fingerprint", &self.parent_fingerprint)
+         .field("child_number", &self.child_number)
+         .finish()
+    }
+}
+
+impl FromStr for ExtendedPrivKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ExtendedPrivKey, Error> {
+        #[allow(unused_mut)]
+        let mut data = base58check_decode(s).ok_or(ErrorKind::InvalidExtendedKey)?;
+        let key = Self::deserialize(&data);
+
+        #[cfg(feature = "zeroize")]
+        data.zeroize();
+
+        key
+    }
+}
+
+impl ExtendedPrivKey {
+    fn deserialize(data: &[u8]) -> Result<ExtendedPrivKey, Error> {
+        let raw = RawKey::deserialize(data)?;
+
+        let network = match raw.version {
+            VERSION_XPRV => Network::Mainnet,
+            VERSION_TPRV => Network::Testnet,
+            _ => Err(ErrorKind::InvalidExtendedKey)?,
+        };
+
+        let key_data = &raw.key_data;
+
+        if key_data[0] != 0 || scalar_from_bytes(&key_data[1..]).map_or(true, |key| bool::from(key.is_zero())) {
+            Err(ErrorKind::InvalidExtendedKey)?;
+        }
+
+        let mut private_key = [0u8; 32];
+
+        private_key.copy_from_slice(&key_data[1..]);
+
+        Ok(ExtendedPrivKey {
+            network,
+            depth: raw.depth,
+            parent_fingerprint: raw.parent_fingerprint,
+            child_number: raw.child_number,
+            chain_code: raw.chain_code,
+            private_key,
+        })
+    }
+}
+
+/// An extended public key, from which the public keys of normal children can be derived
+///
+/// Get one from an [`ExtendedPrivKey`][ExtendedPrivKey] with [`ExtendedPrivKey::to_extended_pub_key()`][ExtendedPrivKey::to_extended_pub_key()],
+/// or by parsing an `xpub...` or `tpub...` string.
+///
+/// [ExtendedPrivKey]: ./struct.ExtendedPrivKey.html
+/// [ExtendedPrivKey::to_extended_pub_key()]: ./struct.ExtendedPrivKey.html#method.to_extended_pub_key
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedPubKey {
+    network: Network,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: [u8; 32],
+    public_key: [u8; 33],
+}
+
+impl ExtendedPubKey {
+    /// Derive the child key at `child`
+    ///
+    /// Hardened children can only be derived from an [`ExtendedPrivKey`][ExtendedPrivKey], asking
+    /// for one returns an `Error` of kind `ErrorKind::HardenedDerivationFromPublicKey`.
+    ///
+    /// [ExtendedPrivKey]: ./struct.ExtendedPrivKey.html
+    pub fn derive_child(&self, child: ChildNumber) -> Result<ExtendedPubKey, Error> {
+        if child.is_hardened() {
+            Err(ErrorKind::HardenedDerivationFromPublicKey)?;
+        }
+
+        let raw = child.to_raw()?.to_be_bytes();
+        let depth = self.depth.checked_add(1).ok_or(ErrorKind::InvalidDerivedKey)?;
+
+        let output = hmac_sha512(&self.chain_code, &[&self.public_key, &raw]);
+        let tweak = scalar_from_bytes(&output[..32]).ok_or(ErrorKind::InvalidDerivedKey)?;
+
+        let parent = PublicKey::from_sec1_bytes(&self.public_key).map_err(|_| ErrorKind::InvalidExtendedKey)?;
+        let point = ProjectivePoint::GENERATOR * tweak + parent.to_projective();
+
+        if point == ProjectivePoint::IDENTITY {
+            Err(ErrorKind::InvalidDerivedKey)?;
+        }
+
+        let mut chain_code = [0u8; 32];
+
+        chain_code.copy_from_slice(&output[32..]);
+
+        Ok(ExtendedPubKey {
+            network: self.network,
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: child,
+            chain_code,
+            public_key: public_key_bytes(&point),
+        })
+    }
+
+    /// Derive the descendant at `path`, relative to this key
+    ///
+    /// The path may only contain normal children.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<ExtendedPubKey, Error> {
+        let mut key = self.clone();
+
+        for child in path {
+            key = key.derive_child(*child)?;
+        }
+
+        Ok(key)
+    }
+
+    /// Get the compressed 33 byte public key
+    pub fn public_key(&self) -> &[u8; 33] {
+        &self.public_key
+    }
+
+    /// Get the chain code
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Get the [`Network`][Network] the key is serialized for
+    ///
+    /// [Network]: ./enum.Network.html
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Get the number of derivation steps from the master key, which is at depth 0
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Get the fingerprint of the parent key, or zeroes for a master key
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// Get the [`ChildNumber`][ChildNumber] this key was derived with
+    ///
+    /// [ChildNumber]: ./enum.ChildNumber.html
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Get the key identifier, the HASH160 of the public key
+    pub fn identifier(&self) -> [u8; 20] {
+        hash160(&self.public_key)
+    }
+
+    /// Get the first 4 bytes of the key identifier, as used for `parent_fingerprint` by children
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let mut fingerprint = [0u8; 4];
+
+        fingerprint.copy_from_slice(&self.identifier()[..4]);
+
+        fingerprint
+    }
+
+    fn deserialize(data: &[u8]) -> Result<ExtendedPubKey, Error> {
+        let raw = RawKey::deserialize(data)?;
+
+        let network = match raw.version {
+            VERSION_XPUB => Network::Mainnet,
+            VERSION_TPUB => Network::Testnet,
+            _ => Err(ErrorKind::InvalidExtendedKey)?,
+        };
+
+        PublicKey::from_sec1_bytes(&raw.key_data).map_err(|_| ErrorKind::InvalidExtendedKey)?;
+
+        Ok(ExtendedPubKey {
+            network,
+            depth: raw.depth,
+            parent_fingerprint: raw.parent_fingerprint,
+            child_number: raw.child_number,
+            chain_code: raw.chain_code,
+            public_key: raw.key_data,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPubKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let serialized = serialize(
+            self.network.public_version(),
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &self.public_key,
+        );
+
+        f.write_str(&base58check_encode(&serialized))
+    }
+}
+
+impl fmt::Debug for ExtendedPubKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for ExtendedPubKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ExtendedPubKey, Error> {
+        let data = base58check_decode(s).ok_or(ErrorKind::InvalidExtendedKey)?;
+
+        Self::deserialize(&data)
+    }
+}
+
+/// Parse 32 big endian bytes as a scalar, returning `None` if they are not below the curve order
+fn scalar_from_bytes(bytes: &[u8]) -> Option<Scalar> {
+    let mut repr = FieldBytes::default();
+
+    repr.copy_from_slice(bytes);
+
+    Option::from(Scalar::from_repr(repr))
+}
+
+fn public_key_bytes(point: &ProjectivePoint) -> [u8; 33] {
+    let mut bytes = [0u8; 33];
+
+    bytes.copy_from_slice(point.to_affine().to_encoded_point(true).as_bytes());
+
+    bytes
+}
+
+fn serialize(
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: &[u8; 32],
+    key_data: &[u8; 33],
+) -> [u8; SERIALIZED_LEN] {
+    // Keys are only ever created with valid child numbers
+    let raw = child_number.to_raw().expect("child number is always valid");
+
+    let mut serialized = [0u8; SERIALIZED_LEN];
+
+    serialized[0..4].copy_from_slice(&version);
+    serialized[4] = depth;
+    serialized[5..9].copy_from_slice(&parent_fingerprint);
+    serialized[9..13].copy_from_slice(&raw.to_be_bytes());
+    serialized[13..45].copy_from_slice(chain_code);
+    serialized[45..78].copy_from_slice(key_data);
+
+    serialized
+}
+
+/// The fields of a serialized extended key, before the key data is interpreted
+struct RawKey {
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: [u8; 32],
+    key_data: [u8; 33],
+}
+
+impl RawKey {
+    fn deserialize(data: &[u8]) -> Result<RawKey, Error> {
+        if data.len() != SERIALIZED_LEN {
+            Err(ErrorKind::InvalidExtendedKey)?;
+        }
+
+        let mut raw = RawKey {
+            version: [0; 4],
+            depth: data[4],
+            parent_fingerprint: [0; 4],
+            child_number: ChildNumber::Normal(0),
+            chain_code: [0; 32],
+            key_data: [0; 33],
+        };
+        let mut child_number = [0u8; 4];
+
+        raw.version.copy_from_slice(&data[0..4]);
+        raw.parent_fingerprint.copy_from_slice(&data[5..9]);
+        child_number.copy_from_slice(&data[9..13]);
+        raw.chain_code.copy_from_slice(&data[13..45]);
+        raw.key_data.copy_from_slice(&data[45..78]);
+
+        raw.child_number = ChildNumber::from(u32::from_be_bytes(child_number));
+
+        // A master key has neither a parent nor a child number
+        if raw.depth == 0 && (raw.parent_fingerprint != [0; 4] || raw.child_number != ChildNumber::Normal(0)) {
+            Err(ErrorKind::InvalidExtendedKey)?;
+        }
+
+        Ok(raw)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for RawKey {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+        self.key_data.zeroize();
+    }
+}
@@ -0,0 +1,275 @@
+//! [BIP0085][bip85-standard] deterministic entropy from a single [`Seed`][Seed]
+//!
+//! Child mnemonics, keys and passwords are derived from a BIP0032 root key along a hardened
+//! path, so backing up the root is enough to recover all of them. A derived child reveals nothing
+//! about the root or its other children.
+//!
+//! Only available with the `bip85` feature, which is off by default.
+//!
+//! ```rust
+//! use bip39::{Mnemonic, MnemonicType, Language, Seed};
+//! use bip39::bip85::RootKey;
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! let root = RootKey::new(&Seed::new(&mnemonic, "")).unwrap();
+//!
+//! // a 24 word wallet for a hardware device, and one for a mobile phone
+//! let hardware = root.mnemonic(MnemonicType::Words24, Language::English, 0).unwrap();
+//! let mobile = root.mnemonic(MnemonicType::Words12, Language::English, 1).unwrap();
+//!
+//! let password = root.password_base85(20, 0).unwrap();
+//! ```
+//!
+//! [bip85-standard]: https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+//! [Seed]: ../seed/struct.Seed.html
+//!
+
+use bip32::{ChildNumber, DerivationPath, ExtendedPrivKey, Network};
+use crypto::hmac_sha512;
+use error::ErrorKind;
+use error::Error;
+use language::Language;
+use mnemonic::Mnemonic;
+use mnemonic_type::MnemonicType;
+use seed::Seed;
+use std::fmt;
+use util::{base58check_encode, base64_encode, base85_encode};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const PURPOSE: u32 = 83_696_968;
+const ENTROPY_KEY: &[u8] = b"bip-entropy-from-k";
+
+const APPLICATION_BIP39: u32 = 39;
+const APPLICATION_WIF: u32 = 2;
+const APPLICATION_XPRV: u32 = 32;
+const APPLICATION_HEX: u32 = 128_169;
+const APPLICATION_BASE64: u32 = 707_764;
+const APPLICATION_BASE85: u32 = 707_785;
+
+/// The root key children are derived from, usually the BIP0032 master key of a [`Seed`][Seed]
+///
+/// Every child is identified by an application specific `index`, which must be below 2^31.
+///
+/// [Seed]: ../seed/struct.Seed.html
+#[derive(Clone)]
+pub struct RootKey {
+    root: ExtendedPrivKey,
+}
+
+impl RootKey {
+    /// Create the root from the BIP0032 master key of a [`Seed`][Seed]
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn new(seed: &Seed) -> Result<RootKey, Error> {
+        Ok(RootKey {
+            root: ExtendedPrivKey::new_master(seed, Network::Mainnet)?,
+        })
+    }
+
+    /// Use an existing extended private key, such as one parsed from an `xprv...` string, as the root
+    pub fn from_extended_key(root: ExtendedPrivKey) -> RootKey {
+        RootKey {
+            root,
+        }
+    }
+
+    /// Derive the 64 bytes of entropy at `path`
+    ///
+    /// This is the building block of all the applications, exposed for ones that aren't
+    /// implemented here. The path should start with the BIP0085 purpose `83696968'`, and only use
+    /// hardened children.
+    ///
+    /// **Note:** The entropy is returned by value, so it is the caller's responsibility to wipe it.
+    pub fn derive_entropy(&self, path: &DerivationPath) -> Result<[u8; 64], Error> {
+        let child = self.root.derive_path(path)?;
+
+        Ok(hmac_sha512(ENTROPY_KEY, &[child.private_key()]))
+    }
+
+    fn application_entropy(&self, application: &[u32]) -> Result<[u8; 64], Error> {
+        let path: Vec<_> = Some(&PURPOSE).into_iter()
+                                         .chain(application)
+                                         .map(|&index| ChildNumber::Hardened(index))
+                                         .collect();
+
+        self.derive_entropy(&DerivationPath::from(path))
+    }
+
+    /// Derive a child [`Mnemonic`][Mnemonic] of any length and language
+    ///
+    /// Returns an `Error` of kind `ErrorKind::CustomLanguage` for a custom word list, since the
+    /// derivation path depends on the language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{MnemonicType, Language};
+    /// use bip39::bip85::RootKey;
+    ///
+    /// let xprv = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+    /// let root = RootKey::from_extended_key(xprv.parse().unwrap());
+    ///
+    /// let mnemonic = root.mnemonic(MnemonicType::Words12, Language::English, 0).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose");
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn mnemonic(&self, mtype: MnemonicType, lang: Language, index: u32) -> Result<Mnemonic, Error> {
+        let application = [APPLICATION_BIP39, language_code(lang)?, mtype.word_count() as u32, index];
+
+        #[allow(unused_mut)]
+        let mut entropy = self.application_entropy(&application)?;
+        let mnemonic = Mnemonic::from_entropy(&entropy[..mtype.entropy_bits() / 8], lang);
+
+        #[cfg(feature = "zeroize")]
+        entropy.zeroize();
+
+        mnemonic
+    }
+
+    /// Derive `num_bytes` of entropy as a lowercase hex string
+    ///
+    /// Between 16 and 64 bytes can be derived, any other length returns an `Error` of kind
+    /// `ErrorKind::InvalidApplicationLength`.
+    pub fn hex(&self, num_bytes: usize, index: u32) -> Result<String, Error> {
+        if !(16..=64).contains(&num_bytes) {
+            Err(ErrorKind::InvalidApplicationLength(num_bytes))?;
+        }
+
+        #[allow(unused_mut)]
+        let mut entropy = self.application_entropy(&[APPLICATION_HEX, num_bytes as u32, index])?;
+        let mut hex = String::with_capacity(num_bytes * 2);
+
+        for byte in &entropy[..num_bytes] {
+            fmt::Write::write_fmt(&mut hex, format_args!("{:02x}", byte))?;
+        }
+
+        #[cfg(feature = "zeroize")]
+        entropy.zeroize();
+
+        Ok(hex)
+    }
+
+    /// Derive a compressed mainnet private key in Wallet Import Format, for use with HD-seed
+    /// wallets like Bitcoin Core
+    pub fn wif(&self, index: u32) -> Result<String, Error> {
+        #[allow(unused_mut)]
+        let mut entropy = self.application_entropy(&[APPLICATION_WIF, index])?;
+        let mut data = [0u8; 34];
+
+        data[0] = 0x80;
+        data[1..33].copy_from_slice(&entropy[..32]);
+        data[33] = 0x01;
+
+        let wif = base58check_encode(&data);
+
+        #[cfg(feature = "zeroize")]
+        {
+            entropy.zeroize();
+            data.zeroize();
+        }
+
+        Ok(wif)
+    }
+
+    /// Derive a mainnet BIP0032 master key
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidDerivedKey` in the astronomically unlikely
+    /// case that the derived key is invalid, in which case the next index should be used.
+    pub fn xprv(&self, index: u32) -> Result<ExtendedPrivKey, Error> {
+        let mut entropy = self.application_entropy(&[APPLICATION_XPRV, index])?;
+
+        // BIP0085 puts the chain code first, the other way around from the BIP0032 HMAC output
+        entropy.rotate_left(32);
+
+        let key = ExtendedPrivKey::from_hmac_output(&entropy, Network::Mainnet, 0, [0; 4], ChildNumber::Normal(0), None);
+
+        #[cfg(feature = "zeroize")]
+        entropy.zeroize();
+
+        key
+    }
+
+    /// Derive a password of `length` Base64 characters
+    ///
+    /// Passwords are between 20 and 86 characters long, any other length returns an `Error` of
+    /// kind `ErrorKind::InvalidApplicationLength`.
+    pub fn password_base64(&self, length: usize, index: u32) -> Result<String, Error> {
+        if !(20..=86).contains(&length) {
+            Err(ErrorKind::InvalidApplicationLength(length))?;
+        }
+
+        #[allow(unused_mut)]
+        let mut entropy = self.application_entropy(&[APPLICATION_BASE64, length as u32, index])?;
+        #[allow(unused_mut)]
+        let mut encoded = base64_encode(&entropy);
+        let password = encoded[..length].to_string();
+
+        #[cfg(feature = "zeroize")]
+        {
+            entropy.zeroize();
+            encoded.zeroize();
+        }
+
+        Ok(password)
+    }
+
+    /// Derive a password of `length` Base85 characters
+    ///
+    /// Passwords are between 10 and 80 characters long, any other length returns an `Error` of
+    /// kind `ErrorKind::InvalidApplicationLength`.
+    pub fn password_base85(&self, length: usize, index: u32) -> Result<String, Error> {
+        if !(10..=80).contains(&length) {
+            Err(ErrorKind::InvalidApplicationLength(length))?;
+        }
+
+        #[allow(unused_mut)]
+        let mut entropy = self.application_entropy(&[APPLICATION_BASE85, length as u32, index])?;
+        #[allow(unused_mut)]
+        let mut encoded = base85_encode(&entropy);
+        let password = encoded[..length].to_string();
+
+        #[cfg(feature = "zeroize")]
+        {
+            entropy.zeroize();
+            encoded.zeroize();
+        }
+
+        Ok(password)
+    }
+}
+
+impl fmt::Debug for RootKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RootKey").finish()
+    }
+}
+
+/// The BIP0085 code of a word list, which is part of the derivation path of a child mnemonic
+fn language_code(lang: Language) -> Result<u32, Error> {
+    Ok(match lang {
+        Language::English => 0,
+        #[cfg(feature = "japanese")]
+        Language::Japanese => 1,
+        #[cfg(feature = "korean")]
+        Language::Korean => 2,
+        #[cfg(feature = "spanish")]
+        Language::Spanish => 3,
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified => 4,
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional => 5,
+        #[cfg(feature = "french")]
+        Language::French => 6,
+        #[cfg(feature = "italian")]
+        Language::Italian => 7,
+        #[cfg(feature = "czech")]
+        Language::Czech => 8,
+        #[cfg(feature = "portuguese")]
+        Language::Portuguese => 9,
+        Language::Custom(_) => Err(ErrorKind::CustomLanguage)?,
+    })
+}
@@ -5,10 +5,20 @@
 //! [Seed]: ../seed/struct.Seed.html
 //!
 
-extern crate rand;
-use self::rand::{ thread_rng, RngCore };
+#[cfg(feature = "slip39")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use error::{Error, ErrorKind};
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+#[cfg(feature = "slip39")]
+use rand::{CryptoRng, RngCore};
 use sha2::Digest;
-use hmac::Hmac;
+use hmac::{Hmac, Mac};
+#[cfg(feature = "bip32")]
+use ripemd160::Ripemd160;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 const PBKDF2_ROUNDS: usize = 2048;
 const PBKDF2_BYTES: usize = 64;
@@ -16,28 +26,175 @@ const PBKDF2_BYTES: usize = 64;
 /// SHA256 helper function, internal to the crate
 ///
 pub(crate) fn sha256_first_byte(input: &[u8]) -> u8 {
-    sha2::Sha256::digest(input).as_ref()[0]
+    #[allow(unused_mut)]
+    let mut hash = sha2::Sha256::digest(input);
+    let first = hash[0];
+
+    #[cfg(feature = "zeroize")]
+    hash.as_mut_slice().zeroize();
+
+    first
+}
+
+/// The operating system RNG, used by the `try_new()` constructors
+///
+/// Returns an `Error` of kind `ErrorKind::RngFailure` if it can't be opened.
+#[cfg(feature = "std")]
+pub(crate) fn os_rng() -> Result<OsRng, Error> {
+    match OsRng::new() {
+        Ok(rng) => Ok(rng),
+        Err(error) => Err(ErrorKind::RngFailure(error))?,
+    }
 }
 
-/// Random byte generator, used to create new mnemonics
+/// Random byte generator, used to create new SLIP-0039 shares
 ///
-pub(crate) fn gen_random_bytes(byte_length: usize) -> Vec<u8> {
-    let mut rng = thread_rng();
+/// Returns an `Error` of kind `ErrorKind::RngFailure` if `rng` fails.
+#[cfg(feature = "slip39")]
+pub(crate) fn gen_random_bytes<R>(rng: &mut R, byte_length: usize) -> Result<Vec<u8>, Error>
+where
+    R: RngCore + CryptoRng + ?Sized,
+{
     let mut bytes = vec![0u8; byte_length];
 
-    rng.fill_bytes(&mut bytes);
+    if let Err(error) = rng.try_fill_bytes(&mut bytes) {
+        Err(ErrorKind::RngFailure(error))?;
+    }
 
-    bytes
+    Ok(bytes)
 }
+
 /// PBKDF2 helper, used to generate [`Seed`][Seed] from [`Mnemonic`][Mnemonic]
 ///
+/// A seed is exactly one block of HMAC-SHA512 output, so only the first block of PBKDF2 is
+/// computed. The salt is fed to HMAC a character at a time, which lets callers normalize it on
+/// the fly rather than collecting it into a `String` first. Any state kept on the stack by the
+/// `hmac` crate is outside of our control.
+///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
 ///
-pub(crate) fn pbkdf2(input: &[u8], salt: &str) -> Vec<u8> {
-    let mut seed = vec![0u8; PBKDF2_BYTES];
+pub(crate) fn pbkdf2<S>(input: &[u8], salt: S) -> [u8; PBKDF2_BYTES]
+where
+    S: IntoIterator<Item = char>,
+{
+    let prf = Hmac::<sha2::Sha512>::new_varkey(input).expect("HMAC accepts keys of any length");
+    let mut mac = prf.clone();
+    let mut utf8 = [0u8; 4];
+
+    for c in salt {
+        mac.input(c.encode_utf8(&mut utf8).as_bytes());
+    }
+
+    // the index of the block, counting from 1
+    mac.input(&1u32.to_be_bytes());
+
+    let mut block = [0u8; PBKDF2_BYTES];
+    let mut seed = [0u8; PBKDF2_BYTES];
+
+    block.copy_from_slice(&mac.result().code());
+    seed.copy_from_slice(&block);
+
+    for _ in 1..PBKDF2_ROUNDS {
+        let mut mac = prf.clone();
 
-    pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(input, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        mac.input(&block);
+        block.copy_from_slice(&mac.result().code());
+
+        for (byte, round) in seed.iter_mut().zip(block.iter()) {
+            *byte ^= round;
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    {
+        utf8.zeroize();
+        block.zeroize();
+    }
 
     seed
 }
+
+/// HMAC-SHA512 helper, used for hierarchical deterministic key derivation
+///
+#[cfg(any(feature = "bip32", feature = "slip10", feature = "electrum"))]
+pub(crate) fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<sha2::Sha512>::new_varkey(key).expect("HMAC accepts keys of any length");
+
+    for chunk in data {
+        mac.input(chunk);
+    }
+
+    let mut output = [0u8; 64];
+
+    output.copy_from_slice(&mac.result().code());
+
+    output
+}
+
+/// Double SHA256 helper, used for Base58Check checksums
+///
+#[cfg(feature = "bip32")]
+pub(crate) fn sha256d(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+
+    output.copy_from_slice(&sha2::Sha256::digest(&sha2::Sha256::digest(input)));
+
+    output
+}
+
+/// RIPEMD160(SHA256) helper, used for BIP0032 key identifiers
+///
+#[cfg(feature = "bip32")]
+pub(crate) fn hash160(input: &[u8]) -> [u8; 20] {
+    let mut output = [0u8; 20];
+
+    output.copy_from_slice(&Ripemd160::digest(&sha2::Sha256::digest(input)));
+
+    output
+}
+
+/// PBKDF2-HMAC-SHA256 helper, used as the round function when encrypting SLIP-0039 master secrets
+///
+#[cfg(feature = "slip39")]
+pub(crate) fn pbkdf2_sha256(password: &[u8], salt: &[u8], rounds: usize, output: &mut [u8]) {
+    pbkdf2::pbkdf2::<Hmac<sha2::Sha256>>(password, salt, rounds, output);
+}
+
+/// HMAC-SHA256 helper, used for the digest share of SLIP-0039 secret sharing
+///
+#[cfg(feature = "slip39")]
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<sha2::Sha256>::new_varkey(key).expect("HMAC accepts keys of any length");
+
+    mac.input(data);
+
+    let mut output = [0u8; 32];
+
+    output.copy_from_slice(&mac.result().code());
+
+    output
+}
+
+/// Electrum's key stretching for pre-2.0 seeds: 100,000 rounds of SHA256 over the previous
+/// round's output followed by the `seed`
+///
+#[cfg(feature = "electrum")]
+pub(crate) fn electrum_v1_stretch(seed: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = sha2::Sha256::new();
+
+    hasher.input(seed);
+    hasher.input(seed);
+    output.copy_from_slice(&hasher.result());
+
+    for _ in 1..100_000 {
+        let mut hasher = sha2::Sha256::new();
+
+        hasher.input(&output[..]);
+        hasher.input(seed);
+        output.copy_from_slice(&hasher.result());
+    }
+
+    output
+}
@@ -0,0 +1,750 @@
+//! [Electrum][electrum-seed] seed-version mnemonics, and telling them apart from BIP0039 phrases
+//!
+//! Since version 2.0 Electrum creates phrases from the same English word list as BIP0039, but
+//! without a checksum. Instead, the HMAC-SHA512 of the phrase keyed with `"Seed version"` starts
+//! with a version prefix that identifies the type of wallet. The [`Seed`][Seed] is derived like a
+//! BIP0039 seed, except that the salt is `"electrum"` followed by the passphrase.
+//!
+//! Because both formats share a word list, a phrase can be valid as both, which is why
+//! [`PhraseKind::for_phrase()`][PhraseKind::for_phrase()] reports on each of them. Seeds of
+//! Electrum versions before 2.0 use their own word list and encoding, see
+//! [`ElectrumV1`][ElectrumV1].
+//!
+//! Only available with the `electrum` feature, which is off by default.
+//!
+//! ```rust
+//! use bip39::Seed;
+//! use bip39::electrum::{ElectrumMnemonic, ElectrumSeedType};
+//!
+//! let mnemonic = ElectrumMnemonic::new(ElectrumSeedType::Segwit);
+//! let seed = Seed::new_electrum(&mnemonic, "");
+//!
+//! println!("{} {:x}", mnemonic.phrase(), seed);
+//! ```
+//!
+//! [electrum-seed]: https://electrum.readthedocs.io/en/latest/seedphrase.html
+//! [Seed]: ../seed/struct.Seed.html
+//! [PhraseKind::for_phrase()]: ./struct.PhraseKind.html#method.for_phrase
+//! [ElectrumV1]: ./struct.ElectrumV1.html
+//!
+
+use crypto::{electrum_v1_stretch, hmac_sha512, os_rng};
+use error::ErrorKind;
+use error::Error;
+#[cfg(feature = "bip32")]
+use k256::SecretKey;
+#[cfg(feature = "bip32")]
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use language::Language;
+use mnemonic::Mnemonic;
+use rand::{thread_rng, CryptoRng, RngCore};
+use std::fmt;
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::UnicodeNormalization;
+use util::{Bits11, IterExt};
+use wordlists;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const VERSION_KEY: &[u8] = b"Seed version";
+const WORD_COUNT: usize = 12;
+
+/// The word list of pre-2.0 seeds, from `electrum/old_mnemonic.py` in the Electrum source
+static V1_WORDLIST: &[&str; 1626] = &wordlists::ELECTRUM_V1;
+
+/// The type of wallet an Electrum phrase was created for, identified by its version prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElectrumSeedType {
+    /// P2PKH wallets, version prefix `01`
+    Standard,
+    /// Native segwit (P2WPKH) wallets, version prefix `100`
+    Segwit,
+    /// TrustedCoin two-factor wallets, version prefix `101`
+    TwoFactor,
+    /// TrustedCoin two-factor segwit wallets, version prefix `102`
+    TwoFactorSegwit,
+}
+
+impl ElectrumSeedType {
+    /// Get the `ElectrumSeedType` of a phrase
+    ///
+    /// Like Electrum, this only looks at the version prefix, so words don't have to come from the
+    /// English word list. A phrase without a known version prefix returns an `Error` of kind
+    /// `ErrorKind::InvalidSeedVersion`.
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::electrum::ElectrumSeedType;
+    ///
+    /// let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+    ///
+    /// assert_eq!(ElectrumSeedType::for_phrase(phrase).unwrap(), ElectrumSeedType::Segwit);
+    /// ```
+    pub fn for_phrase(phrase: &str) -> Result<ElectrumSeedType, Error> {
+        #[allow(unused_mut)]
+        let mut normalized = normalize_text(phrase);
+        let seed_type = Self::for_normalized(&normalized);
+
+        #[cfg(feature = "zeroize")]
+        normalized.zeroize();
+
+        seed_type
+    }
+
+    fn for_normalized(normalized: &str) -> Result<ElectrumSeedType, Error> {
+        let version = hmac_sha512(VERSION_KEY, &[normalized.as_bytes()]);
+        let prefix = format!("{:02x}{:02x}", version[0], version[1]);
+
+        let seed_type = [
+            ElectrumSeedType::Standard,
+            ElectrumSeedType::Segwit,
+            ElectrumSeedType::TwoFactor,
+            ElectrumSeedType::TwoFactorSegwit,
+        ].iter().find(|seed_type| prefix.starts_with(seed_type.version_prefix()));
+
+        match seed_type {
+            Some(seed_type) => Ok(*seed_type),
+            None => Err(ErrorKind::InvalidSeedVersion)?,
+        }
+    }
+
+    /// Get the hex prefix of the seed version for this type
+    pub fn version_prefix(&self) -> &'static str {
+        match *self {
+            ElectrumSeedType::Standard => "01",
+            ElectrumSeedType::Segwit => "100",
+            ElectrumSeedType::TwoFactor => "101",
+            ElectrumSeedType::TwoFactorSegwit => "102",
+        }
+    }
+}
+
+impl fmt::Display for ElectrumSeedType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ElectrumSeedType::Standard => "standard",
+            ElectrumSeedType::Segwit => "segwit",
+            ElectrumSeedType::TwoFactor => "2fa",
+            ElectrumSeedType::TwoFactorSegwit => "2fa_segwit",
+        })
+    }
+}
+
+/// An Electrum seed-version mnemonic phrase
+///
+/// To create a *new* phrase call [`ElectrumMnemonic::new()`][ElectrumMnemonic::new()], for an
+/// existing one use [`ElectrumMnemonic::from_phrase()`][ElectrumMnemonic::from_phrase()]. The
+/// [`Seed`][Seed] of a wallet is derived with [`Seed::new_electrum()`][Seed::new_electrum()].
+///
+/// With the `zeroize` feature (enabled by default) the phrase is wiped from memory when dropped.
+///
+/// [ElectrumMnemonic::new()]: ./struct.ElectrumMnemonic.html#method.new
+/// [ElectrumMnemonic::from_phrase()]: ./struct.ElectrumMnemonic.html#method.from_phrase
+/// [Seed]: ../seed/struct.Seed.html
+/// [Seed::new_electrum()]: ../seed/struct.Seed.html#method.new_electrum
+#[derive(Clone)]
+pub struct ElectrumMnemonic {
+    phrase: String,
+    seed_type: ElectrumSeedType,
+}
+
+impl ElectrumMnemonic {
+    /// Generates a new 12 word English [`ElectrumMnemonic`][ElectrumMnemonic] of `seed_type`
+    ///
+    /// Like Electrum, this starts from 132 bits of entropy and counts up until the phrase has the
+    /// right version prefix. Phrases that happen to be valid BIP0039 or pre-2.0
+    /// [`ElectrumV1`][ElectrumV1] phrases as well are skipped, so the result is never ambiguous.
+    ///
+    /// The entropy comes from the thread local RNG, which panics if it can't be seeded by the
+    /// operating system. [`ElectrumMnemonic::try_new()`][ElectrumMnemonic::try_new()] returns an
+    /// error instead.
+    ///
+    /// [ElectrumMnemonic]: ./struct.ElectrumMnemonic.html
+    /// [ElectrumV1]: ./struct.ElectrumV1.html
+    /// [ElectrumMnemonic::try_new()]: ./struct.ElectrumMnemonic.html#method.try_new
+    pub fn new(seed_type: ElectrumSeedType) -> ElectrumMnemonic {
+        ElectrumMnemonic::new_with_rng(seed_type, &mut thread_rng())
+    }
+
+    /// Generates a new [`ElectrumMnemonic`][ElectrumMnemonic] with entropy from the operating
+    /// system
+    ///
+    /// Unlike [`ElectrumMnemonic::new()`][ElectrumMnemonic::new()], this returns an `Error` of kind
+    /// `ErrorKind::RngFailure` if the operating system RNG fails, rather than panicking.
+    ///
+    /// [ElectrumMnemonic]: ./struct.ElectrumMnemonic.html
+    /// [ElectrumMnemonic::new()]: ./struct.ElectrumMnemonic.html#method.new
+    pub fn try_new(seed_type: ElectrumSeedType) -> Result<ElectrumMnemonic, Error> {
+        ElectrumMnemonic::try_new_with_rng(seed_type, &mut os_rng()?)
+    }
+
+    /// Generates a new [`ElectrumMnemonic`][ElectrumMnemonic] with entropy from `rng`
+    ///
+    /// Panics if the RNG fails, use
+    /// [`ElectrumMnemonic::try_new_with_rng()`][ElectrumMnemonic::try_new_with_rng()] for one
+    /// that can.
+    ///
+    /// [ElectrumMnemonic]: ./struct.ElectrumMnemonic.html
+    /// [ElectrumMnemonic::try_new_with_rng()]: ./struct.ElectrumMnemonic.html#method.try_new_with_rng
+    pub fn new_with_rng<R>(seed_type: ElectrumSeedType, rng: &mut R) -> ElectrumMnemonic
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        ElectrumMnemonic::try_new_with_rng(seed_type, rng).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Generates a new [`ElectrumMnemonic`][ElectrumMnemonic] with entropy from an `rng` that can
+    /// fail
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` with the error of the RNG if it fails.
+    ///
+    /// [ElectrumMnemonic]: ./struct.ElectrumMnemonic.html
+    pub fn try_new_with_rng<R>(seed_type: ElectrumSeedType, rng: &mut R) -> Result<ElectrumMnemonic, Error>
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let wordlist = Language::English.wordlist();
+
+        loop {
+            let mut entropy = [0u8; 17];
+
+            if let Err(error) = rng.try_fill_bytes(&mut entropy) {
+                Err(ErrorKind::RngFailure(error))?;
+            }
+
+            // The words encode a number in base 2048, least significant first. The most
+            // significant word must not be zero, or Electrum would encode it with fewer words.
+            let mut words: Vec<u16> = entropy.iter().bits().take(WORD_COUNT).map(|bits: Bits11| u16::from(bits)).collect();
+
+            #[cfg(feature = "zeroize")]
+            entropy.zeroize();
+
+            if words[WORD_COUNT - 1] == 0 {
+                continue;
+            }
+
+            while increment(&mut words) {
+                #[allow(unused_mut)]
+                let mut phrase: String = words.iter().map(|&word| wordlist.get_word(Bits11::from(word))).join(" ");
+
+                if is_unambiguous(&phrase, seed_type) {
+                    #[cfg(feature = "zeroize")]
+                    words.zeroize();
+
+                    return Ok(ElectrumMnemonic {
+                        phrase,
+                        seed_type,
+                    });
+                }
+
+                #[cfg(feature = "zeroize")]
+                phrase.zeroize();
+            }
+        }
+    }
+
+    /// Create an [`ElectrumMnemonic`][ElectrumMnemonic] from an existing phrase
+    ///
+    /// The phrase is normalized the way Electrum does: to Unicode NFKD, lower case, without
+    /// accents and with single spaces between words. An `Error` of kind
+    /// `ErrorKind::InvalidSeedVersion` is returned if it doesn't have a known version prefix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::electrum::{ElectrumMnemonic, ElectrumSeedType};
+    ///
+    /// let phrase = "Wild father tree among universe such  mobile favorite target dynamic credit identify";
+    /// let mnemonic = ElectrumMnemonic::from_phrase(phrase).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), "wild father tree among universe such mobile favorite target dynamic credit identify");
+    /// assert_eq!(mnemonic.seed_type(), ElectrumSeedType::Segwit);
+    /// ```
+    ///
+    /// [ElectrumMnemonic]: ./struct.ElectrumMnemonic.html
+    pub fn from_phrase(phrase: &str) -> Result<ElectrumMnemonic, Error> {
+        #[allow(unused_mut)]
+        let mut normalized = normalize_text(phrase);
+
+        match ElectrumSeedType::for_normalized(&normalized) {
+            Ok(seed_type) => Ok(ElectrumMnemonic {
+                phrase: normalized,
+                seed_type,
+            }),
+            Err(error) => {
+                #[cfg(feature = "zeroize")]
+                normalized.zeroize();
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Validate that a phrase is an Electrum phrase of `seed_type`
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidSeedVersion` for phrases of other types.
+    pub fn validate(phrase: &str, seed_type: ElectrumSeedType) -> Result<(), Error> {
+        if ElectrumSeedType::for_phrase(phrase)? != seed_type {
+            Err(ErrorKind::InvalidSeedVersion)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the normalized phrase as a string reference
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Get the [`ElectrumSeedType`][ElectrumSeedType]
+    ///
+    /// [ElectrumSeedType]: ./enum.ElectrumSeedType.html
+    pub fn seed_type(&self) -> ElectrumSeedType {
+        self.seed_type
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ElectrumMnemonic {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+    }
+}
+
+impl AsRef<str> for ElectrumMnemonic {
+    fn as_ref(&self) -> &str {
+        self.phrase()
+    }
+}
+
+impl fmt::Display for ElectrumMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.phrase(), f)
+    }
+}
+
+impl fmt::Debug for ElectrumMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.phrase(), f)
+    }
+}
+
+/// A pre-2.0 Electrum seed, encoded as 12 words from a list of 1626
+///
+/// Each group of 3 words encodes 32 bits of the 128-bit seed in base 1626, rather than the 11
+/// bit chunks of BIP0039, and there is no checksum. The wallet keys aren't derived from the
+/// phrase but from the seed as a hex string, see
+/// [`ElectrumV1::hex_seed()`][ElectrumV1::hex_seed()] and
+/// [`ElectrumV1::stretched_key()`][ElectrumV1::stretched_key()].
+///
+/// With the `zeroize` feature (enabled by default) the seed is wiped from memory when dropped.
+///
+/// [ElectrumV1::hex_seed()]: ./struct.ElectrumV1.html#method.hex_seed
+/// [ElectrumV1::stretched_key()]: ./struct.ElectrumV1.html#method.stretched_key
+#[derive(Clone)]
+pub struct ElectrumV1 {
+    seed: [u8; 16],
+}
+
+impl ElectrumV1 {
+    /// Generates a new random seed
+    ///
+    /// The seed comes from the thread local RNG, which panics if it can't be seeded by the
+    /// operating system. [`ElectrumV1::try_new()`][ElectrumV1::try_new()] returns an error instead.
+    ///
+    /// [ElectrumV1::try_new()]: ./struct.ElectrumV1.html#method.try_new
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> ElectrumV1 {
+        ElectrumV1::new_with_rng(&mut thread_rng())
+    }
+
+    /// Generates a new random seed from the operating system RNG
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the RNG fails.
+    pub fn try_new() -> Result<ElectrumV1, Error> {
+        ElectrumV1::try_new_with_rng(&mut os_rng()?)
+    }
+
+    /// Generates a new random seed from `rng`, panicking if it fails
+    pub fn new_with_rng<R>(rng: &mut R) -> ElectrumV1
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let mut seed = [0u8; 16];
+
+        rng.fill_bytes(&mut seed);
+
+        ElectrumV1 {
+            seed,
+        }
+    }
+
+    /// Generates a new random seed from `rng`
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` with the error of the RNG if it fails.
+    pub fn try_new_with_rng<R>(rng: &mut R) -> Result<ElectrumV1, Error>
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let mut seed = [0u8; 16];
+
+        if let Err(error) = rng.try_fill_bytes(&mut seed) {
+            Err(ErrorKind::RngFailure(error))?;
+        }
+
+        Ok(ElectrumV1 {
+            seed,
+        })
+    }
+
+    /// Create an [`ElectrumV1`][ElectrumV1] from the 16 bytes of an existing seed
+    ///
+    /// Any other length returns an `Error` of kind `ErrorKind::InvalidSeedLength`.
+    ///
+    /// [ElectrumV1]: ./struct.ElectrumV1.html
+    pub fn from_bytes(bytes: &[u8]) -> Result<ElectrumV1, Error> {
+        if bytes.len() != 16 {
+            Err(ErrorKind::InvalidSeedLength(bytes.len()))?;
+        }
+
+        let mut seed = [0u8; 16];
+
+        seed.copy_from_slice(bytes);
+
+        Ok(ElectrumV1 {
+            seed,
+        })
+    }
+
+    /// Create an [`ElectrumV1`][ElectrumV1] from an existing 12 word phrase
+    ///
+    /// The phrase is normalized like other Electrum phrases. Returns an `Error` of kind
+    /// `ErrorKind::InvalidWordLength` if it doesn't have 12 words, `ErrorKind::InvalidWord` for
+    /// words that aren't in the list, and `ErrorKind::InvalidChecksum` if a group of 3 words
+    /// doesn't encode a 32-bit number.
+    ///
+    /// [ElectrumV1]: ./struct.ElectrumV1.html
+    pub fn from_phrase(phrase: &str) -> Result<ElectrumV1, Error> {
+        #[allow(unused_mut)]
+        let mut normalized = normalize_text(phrase);
+        let seed = decode_v1(&normalized);
+
+        #[cfg(feature = "zeroize")]
+        normalized.zeroize();
+
+        Ok(ElectrumV1 {
+            seed: seed?,
+        })
+    }
+
+    /// Validate a 12 word phrase, see [`ElectrumV1::from_phrase()`][ElectrumV1::from_phrase()]
+    ///
+    /// [ElectrumV1::from_phrase()]: ./struct.ElectrumV1.html#method.from_phrase
+    pub fn validate(phrase: &str) -> Result<(), Error> {
+        ElectrumV1::from_phrase(phrase).map(|_| ())
+    }
+
+    /// Encode the seed as a 12 word phrase
+    pub fn phrase(&self) -> String {
+        let n = V1_WORDLIST.len() as u32;
+        let mut indices = Vec::with_capacity(WORD_COUNT);
+
+        for chunk in self.seed.chunks(4) {
+            let x = chunk.iter().fold(0u32, |x, &byte| (x << 8) | byte as u32);
+            let w1 = x % n;
+            let w2 = (x / n + w1) % n;
+            let w3 = (x / n / n + w2) % n;
+
+            indices.extend_from_slice(&[w1, w2, w3]);
+        }
+
+        let phrase = indices.iter().map(|&index| V1_WORDLIST[index as usize]).join(" ");
+
+        #[cfg(feature = "zeroize")]
+        indices.zeroize();
+
+        phrase
+    }
+
+    /// Get the seed as a byte slice
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.seed
+    }
+
+    /// Get the seed as the 32 character lowercase hex string that Electrum stores and shows
+    pub fn hex_seed(&self) -> String {
+        let mut hex = String::with_capacity(32);
+
+        for byte in &self.seed {
+            hex.push_str(&format!("{:02x}", byte));
+        }
+
+        hex
+    }
+
+    /// Get the private key the wallet keys are derived from, by stretching the
+    /// [hex seed][ElectrumV1::hex_seed()] with 100,000 rounds of SHA256
+    ///
+    /// **Note:** The key is returned by value, so it is the caller's responsibility to wipe it.
+    ///
+    /// [ElectrumV1::hex_seed()]: ./struct.ElectrumV1.html#method.hex_seed
+    pub fn stretched_key(&self) -> [u8; 32] {
+        #[allow(unused_mut)]
+        let mut hex = self.hex_seed();
+        let key = electrum_v1_stretch(hex.as_bytes());
+
+        #[cfg(feature = "zeroize")]
+        hex.zeroize();
+
+        key
+    }
+
+    /// Get the master public key of the wallet, the 64 byte uncompressed secp256k1 point of the
+    /// [stretched key][ElectrumV1::stretched_key()] without the `0x04` prefix
+    ///
+    /// Only available with the `bip32` feature. Returns an `Error` of kind
+    /// `ErrorKind::InvalidDerivedKey` in the astronomically unlikely case that the stretched key
+    /// isn't a valid private key.
+    ///
+    /// [ElectrumV1::stretched_key()]: ./struct.ElectrumV1.html#method.stretched_key
+    #[cfg(feature = "bip32")]
+    pub fn master_public_key(&self) -> Result<[u8; 64], Error> {
+        #[allow(unused_mut)]
+        let mut key = self.stretched_key();
+        let secret = SecretKey::from_slice(&key);
+
+        #[cfg(feature = "zeroize")]
+        key.zeroize();
+
+        let point = secret.map_err(|_| ErrorKind::InvalidDerivedKey)?.public_key().to_encoded_point(false);
+        let mut output = [0u8; 64];
+
+        output.copy_from_slice(&point.as_bytes()[1..]);
+
+        Ok(output)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ElectrumV1 {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+impl fmt::Debug for ElectrumV1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ElectrumV1").finish()
+    }
+}
+
+/// Which formats a phrase is valid in, for diagnosing phrases imported from other wallets
+///
+/// # Example
+///
+/// ```
+/// use bip39::Language;
+/// use bip39::electrum::{ElectrumSeedType, PhraseKind};
+///
+/// let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+/// let kind = PhraseKind::for_phrase(phrase, Language::English);
+///
+/// assert!(!kind.is_bip39());
+/// assert_eq!(kind.electrum(), Some(ElectrumSeedType::Segwit));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhraseKind {
+    bip39: bool,
+    electrum: Option<ElectrumSeedType>,
+    electrum_v1: bool,
+}
+
+impl PhraseKind {
+    /// Check a phrase against BIP0039 in `lang`, against the Electrum seed versions, and as a
+    /// pre-2.0 [`ElectrumV1`][ElectrumV1] phrase
+    ///
+    /// [ElectrumV1]: ./struct.ElectrumV1.html
+    pub fn for_phrase(phrase: &str, lang: Language) -> PhraseKind {
+        PhraseKind {
+            bip39: Mnemonic::validate(phrase, lang).is_ok(),
+            electrum: ElectrumSeedType::for_phrase(phrase).ok(),
+            electrum_v1: ElectrumV1::validate(phrase).is_ok(),
+        }
+    }
+
+    /// Whether the phrase is a valid BIP0039 phrase
+    pub fn is_bip39(&self) -> bool {
+        self.bip39
+    }
+
+    /// Get the [`ElectrumSeedType`][ElectrumSeedType] if the phrase is a valid Electrum phrase
+    ///
+    /// [ElectrumSeedType]: ./enum.ElectrumSeedType.html
+    pub fn electrum(&self) -> Option<ElectrumSeedType> {
+        self.electrum
+    }
+
+    /// Whether the phrase is a valid pre-2.0 Electrum phrase
+    pub fn is_electrum_v1(&self) -> bool {
+        self.electrum_v1
+    }
+
+    /// Whether the phrase is valid in more than one format, so the wallet it came from has to be
+    /// known to derive the right seed
+    pub fn is_ambiguous(&self) -> bool {
+        self.formats() > 1
+    }
+
+    /// Whether the phrase isn't valid in any format
+    pub fn is_unknown(&self) -> bool {
+        self.formats() == 0
+    }
+
+    fn formats(&self) -> usize {
+        self.bip39 as usize + self.electrum.is_some() as usize + self.electrum_v1 as usize
+    }
+}
+
+/// Whether a generated phrase has the version of `seed_type`, and can't be mistaken for a BIP0039
+/// or a pre-2.0 Electrum phrase
+fn is_unambiguous(phrase: &str, seed_type: ElectrumSeedType) -> bool {
+    Mnemonic::validate(phrase, Language::English).is_err()
+        && ElectrumSeedType::for_normalized(phrase).ok() == Some(seed_type)
+        && ElectrumV1::validate(phrase).is_err()
+}
+
+/// Decode a normalized 12 word phrase, every 3 words encode 32 bits in base 1626
+fn decode_v1(phrase: &str) -> Result<[u8; 16], Error> {
+    let n = V1_WORDLIST.len() as u64;
+    let mut indices = [0u64; WORD_COUNT];
+    let mut count = 0;
+
+    for word in phrase.split(' ') {
+        if count < WORD_COUNT {
+            indices[count] = V1_WORDLIST.iter().position(|&w| w == word).ok_or_else(|| ErrorKind::InvalidWord(count))? as u64;
+        }
+
+        count += 1;
+    }
+
+    if count != WORD_COUNT {
+        Err(ErrorKind::InvalidWordLength(count))?;
+    }
+
+    let mut seed = [0u8; 16];
+
+    for (chunk, output) in indices.chunks(3).zip(seed.chunks_mut(4)) {
+        let x = chunk[0] + n * ((n + chunk[1] - chunk[0]) % n) + n * n * ((n + chunk[2] - chunk[1]) % n);
+
+        if x > u32::MAX as u64 {
+            Err(ErrorKind::InvalidChecksum)?;
+        }
+
+        output.copy_from_slice(&(x as u32).to_be_bytes());
+    }
+
+    #[cfg(feature = "zeroize")]
+    indices.zeroize();
+
+    Ok(seed)
+}
+
+/// Add one to the number encoded by `words`, least significant first, returning `false` on overflow
+fn increment(words: &mut [u16]) -> bool {
+    for word in words.iter_mut() {
+        if *word < 2047 {
+            *word += 1;
+
+            return true;
+        }
+
+        *word = 0;
+    }
+
+    false
+}
+
+/// Normalize a phrase or passphrase the way Electrum does
+///
+/// That is Unicode NFKD, lower case, with combining marks (accents) removed, words separated by
+/// single spaces and no spaces between CJK characters.
+pub(crate) fn normalize_text(text: &str) -> String {
+    let mut chars: Vec<char> = Vec::with_capacity(text.len());
+
+    for word in text.split_whitespace() {
+        if !chars.is_empty() {
+            chars.push(' ');
+        }
+
+        chars.extend(word.nfkd().flat_map(char::to_lowercase).filter(|&c| canonical_combining_class(c) == 0));
+    }
+
+    let mut normalized = String::with_capacity(text.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        let between_cjk = c == ' ' && is_cjk(chars[i - 1]) && chars.get(i + 1).is_some_and(|&next| is_cjk(next));
+
+        if !between_cjk {
+            normalized.push(c);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    chars.zeroize();
+
+    normalized
+}
+
+/// Whether `c` is a Chinese, Japanese or Korean character, using the same blocks as Electrum
+fn is_cjk(c: char) -> bool {
+    const CJK_INTERVALS: &[(u32, u32)] = &[
+        (0x4E00, 0x9FFF),   // CJK Unified Ideographs
+        (0x3400, 0x4DBF),   // CJK Unified Ideographs Extension A
+        (0x20000, 0x2A6DF), // CJK Unified Ideographs Extension B
+        (0x2A700, 0x2B73F), // CJK Unified Ideographs Extension C
+        (0x2B740, 0x2B81F), // CJK Unified Ideographs Extension D
+        (0xF900, 0xFAFF),   // CJK Compatibility Ideographs
+        (0x2F800, 0x2FA1D), // CJK Compatibility Ideographs Supplement
+        (0x3190, 0x319F),   // Kanbun
+        (0x2E80, 0x2EFF),   // CJK Radicals Supplement
+        (0x2F00, 0x2FDF),   // CJK Radicals
+        (0x31C0, 0x31EF),   // CJK Strokes
+        (0x2FF0, 0x2FFF),   // Ideographic Description Characters
+        (0xE0100, 0xE01EF), // Variation Selectors Supplement
+        (0x3100, 0x312F),   // Bopomofo
+        (0x31A0, 0x31BF),   // Bopomofo Extended
+        (0xFF00, 0xFFEF),   // Halfwidth and Fullwidth Forms
+        (0x3040, 0x309F),   // Hiragana
+        (0x30A0, 0x30FF),   // Katakana
+        (0x31F0, 0x31FF),   // Katakana Phonetic Extensions
+        (0x1B000, 0x1B0FF), // Kana Supplement
+        (0xAC00, 0xD7AF),   // Hangul Syllables
+        (0x1100, 0x11FF),   // Hangul Jamo
+        (0xA960, 0xA97F),   // Hangul Jamo Extended A
+        (0xD7B0, 0xD7FF),   // Hangul Jamo Extended B
+        (0x3130, 0x318F),   // Hangul Compatibility Jamo
+        (0xA4D0, 0xA4FF),   // Lisu
+        (0x16F00, 0x16F9F), // Miao
+        (0xA000, 0xA48F),   // Yi Syllables
+        (0xA490, 0xA4CF),   // Yi Radicals
+    ];
+
+    let c = c as u32;
+
+    CJK_INTERVALS.iter().any(|&(start, end)| start <= c && c <= end)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_skips_v1_phrases() {
+        let phrase = "wrist okay climb kid position sheep hope yearn stick pretty stubborn fought";
+
+        assert_eq!(ElectrumSeedType::for_normalized(phrase).ok(), Some(ElectrumSeedType::Standard));
+        assert!(ElectrumV1::validate(phrase).is_ok());
+        assert!(!is_unambiguous(phrase, ElectrumSeedType::Standard));
+        assert!(!is_unambiguous(phrase, ElectrumSeedType::Segwit));
+    }
+}
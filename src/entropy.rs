@@ -0,0 +1,490 @@
+//! Entropy from dice, coins and playing cards, for generating a [`Mnemonic`][Mnemonic] without
+//! trusting a computer's random number generator
+//!
+//! Each roll, flip, digit or card drawn is a uniformly random value out of a few possible ones,
+//! which is turned into bits without introducing bias: the possible values are split into groups
+//! whose sizes are powers of two, and a value only gives the bits of its position within its
+//! group. A die roll of 1 to 4 gives two bits and a roll of 5 or 6 one bit, so the number of
+//! rolls needed varies. [`Source::needed()`][Source::needed()] tells how many more to make.
+//!
+//! ```rust
+//! use bip39::{Language, Mnemonic, MnemonicType};
+//! use bip39::entropy::Source;
+//!
+//! let rolls = "3614256325146352164531625341263514265341625341625341256345162534162534162534125634";
+//!
+//! assert_eq!(Source::DiceRolls.needed(rolls, MnemonicType::Words12).unwrap(), 0);
+//!
+//! let mnemonic = Mnemonic::from_physical(Source::DiceRolls, rolls, MnemonicType::Words12, Language::English).unwrap();
+//! ```
+//!
+//! Only the first bits that are needed are used, further input doesn't contribute to the
+//! entropy.
+//!
+//! ## Mixing sources
+//!
+//! A [`Mixer`][Mixer] hashes several sources together instead, like the system RNG, dice rolls
+//! and a hardware RNG. The result is as unpredictable as the best of them, so a backdoored RNG or
+//! a loaded die alone doesn't give the mnemonic away. Without a random number generator, physical
+//! sources and files have to make up as many bits as the mnemonic has entropy. Arbitrary bytes
+//! are mixed in as well but count for nothing, as there is no telling how guessable they are.
+//!
+//! ```rust
+//! use bip39::{Language, MnemonicType};
+//! use bip39::entropy::{Mixer, Source};
+//!
+//! let mixer = Mixer::new(MnemonicType::Words24)
+//!     .system_rng().unwrap()
+//!     .physical(Source::DiceRolls, "3614256325146352164531625341").unwrap()
+//!     .bytes(b"typed on a keyboard by a cat");
+//!
+//! assert_eq!(mixer.contributions().len(), 3);
+//!
+//! let mnemonic = mixer.mnemonic(Language::English).unwrap();
+//! ```
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [Mixer]: ./struct.Mixer.html
+//! [Source::needed()]: ./enum.Source.html#method.needed
+//!
+
+use error::{Error, ErrorKind};
+use mnemonic::Mnemonic;
+use mnemonic_type::MnemonicType;
+use language::Language;
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, RngCore};
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+use sha2::Sha256;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::Read;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const DECK_SIZE: u32 = 52;
+const RANKS: &str = "A23456789TJQK";
+const SUITS: &str = "CDHS";
+
+/// The HMAC key of the [`Mixer`][Mixer], so that its output differs from a plain hash of the input
+///
+/// [Mixer]: ./struct.Mixer.html
+const MIXER_KEY: &[u8] = b"bip39 entropy mixer";
+
+/// Bytes read from the RNG by [`Mixer::rng()`][Mixer::rng()], enough for a 24 word mnemonic
+///
+/// [Mixer::rng()]: ./struct.Mixer.html#method.rng
+const RNG_BYTES: usize = 32;
+
+/// A physical source of randomness, and how its input is written
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Coin flips, written as `0` and `1`
+    CoinFlips,
+    /// Rolls of a six-sided die, written as `1` to `6`
+    DiceRolls,
+    /// Decimal digits `0` to `9`, from a ten-sided die or drawn from a hat
+    Digits,
+    /// Cards drawn from shuffled decks of 52 playing cards, written as the rank
+    /// (`A`, `2` to `9`, `T` or `10`, `J`, `Q`, `K`) followed by the suit (`C`, `D`, `H`, `S`),
+    /// like `AS`, `10H` or `7d`
+    ///
+    /// Each deck goes on its own line, and a deck doesn't have to be drawn to the end. Since
+    /// every card of a deck is drawn from the ones that are left, later cards give fewer bits and
+    /// the last one none at all.
+    Cards,
+}
+
+impl Source {
+    /// Get the number of further rolls, flips, digits or cards needed at least, after `input`,
+    /// for the entropy of `mtype`
+    ///
+    /// This assumes each one gives as many bits as it can, so it may take more: add them to the
+    /// input and ask again, until this returns 0. Every roll, flip or digit gives at least one
+    /// bit, as does every card but the last of a deck.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidEntropySymbol` or `ErrorKind::DuplicateCard`
+    /// if the input so far is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::MnemonicType;
+    /// use bip39::entropy::Source;
+    ///
+    /// assert_eq!(Source::CoinFlips.needed("", MnemonicType::Words12).unwrap(), 128);
+    /// assert_eq!(Source::DiceRolls.needed("", MnemonicType::Words12).unwrap(), 64);
+    /// assert_eq!(Source::DiceRolls.needed("5", MnemonicType::Words12).unwrap(), 64);
+    /// ```
+    pub fn needed(self, input: &str, mtype: MnemonicType) -> Result<usize, Error> {
+        let wanted = mtype.entropy_bits();
+        let extracted = self.extract(input, &mut [0; 32], wanted)?;
+
+        Ok(wanted.saturating_sub(extracted).div_ceil(self.max_bits()))
+    }
+
+    /// The most bits a single symbol can give
+    fn max_bits(self) -> usize {
+        match self {
+            Source::CoinFlips => 1,
+            Source::DiceRolls => 2,
+            Source::Digits => 3,
+            Source::Cards => 5,
+        }
+    }
+
+    /// Turn `input` into bits, writing the first `wanted` of them to `output`
+    ///
+    /// The whole input is checked, and the number of bits it gives is returned, which may be more
+    /// or less than `wanted`.
+    pub(crate) fn extract(self, input: &str, output: &mut [u8], wanted: usize) -> Result<usize, Error> {
+        debug_assert!(wanted <= output.len() * 8, "Output too small for the bits wanted");
+
+        let mut extracted = 0;
+        let mut emit = |value: u32, count: u32| {
+            for i in (0..count).rev() {
+                if extracted < wanted {
+                    output[extracted / 8] |= (((value >> i) & 1) as u8) << (7 - extracted % 8);
+                }
+
+                extracted += 1;
+            }
+        };
+
+        match self {
+            Source::CoinFlips | Source::DiceRolls | Source::Digits => {
+                let (first, base) = match self {
+                    Source::CoinFlips => ('0', 2),
+                    Source::DiceRolls => ('1', 6),
+                    _ => ('0', 10),
+                };
+
+                for (index, c) in input.chars().filter(|c| !c.is_whitespace()).enumerate() {
+                    match (c as u32).checked_sub(first as u32).filter(|&value| value < base) {
+                        Some(value) => split_uniform(value, base, &mut emit),
+                        None => Err(ErrorKind::InvalidEntropySymbol(index))?,
+                    }
+                }
+            }
+            Source::Cards => {
+                let mut index = 0;
+
+                for deck in input.lines() {
+                    // one bit for each card drawn from this deck so far
+                    let mut drawn = 0u64;
+
+                    for card in deck.split_whitespace() {
+                        let value = match parse_card(card) {
+                            Some(value) => value,
+                            None => Err(ErrorKind::InvalidEntropySymbol(index))?,
+                        };
+
+                        if drawn & (1 << value) != 0 {
+                            Err(ErrorKind::DuplicateCard(index))?;
+                        }
+
+                        // The position of the card among the ones left in the deck
+                        let below = (drawn & ((1 << value) - 1)).count_ones();
+
+                        split_uniform(value - below, DECK_SIZE - drawn.count_ones(), &mut emit);
+
+                        drawn |= 1 << value;
+                        index += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(extracted)
+    }
+}
+
+/// A source that went into a [`Mixer`][Mixer]
+///
+/// [Mixer]: ./struct.Mixer.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contribution {
+    /// The operating system's random number generator
+    SystemRng,
+    /// A random number generator passed to [`Mixer::rng()`][Mixer::rng()]
+    ///
+    /// [Mixer::rng()]: ./struct.Mixer.html#method.rng
+    Rng,
+    /// Dice rolls, coin flips, digits or cards, and the number of bits they gave
+    Physical(Source, usize),
+    /// A file like `/dev/hwrng`, and the number of bytes read from it
+    #[cfg(feature = "std")]
+    File(PathBuf, usize),
+    /// Bytes passed to [`Mixer::bytes()`][Mixer::bytes()], and how many there were, which don't
+    /// count towards the entropy of the mnemonic
+    ///
+    /// [Mixer::bytes()]: ./struct.Mixer.html#method.bytes
+    Bytes(usize),
+}
+
+impl Contribution {
+    /// The number of bits this source accounts for, which for a random number generator is all
+    /// that it gave
+    fn bits(&self) -> usize {
+        match *self {
+            Contribution::SystemRng | Contribution::Rng => RNG_BYTES * 8,
+            Contribution::Physical(_, bits) => bits,
+            #[cfg(feature = "std")]
+            Contribution::File(_, length) => length * 8,
+            Contribution::Bytes(_) => 0,
+        }
+    }
+}
+
+/// Combines entropy from several sources into a [`Mnemonic`][Mnemonic]
+///
+/// Every source is fed to HMAC-SHA256, along with what kind of source it is and its length, and
+/// the entropy of the mnemonic is the start of the result. As long as one of the sources is
+/// unpredictable, so is the mnemonic, however the others were chosen.
+///
+/// This is a builder, call [`Mixer::mnemonic()`][Mixer::mnemonic()] once all the sources are in.
+/// Any state kept by the `hmac` crate is outside of our control, and isn't wiped by the
+/// `zeroize` feature.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Mixer::mnemonic()]: ./struct.Mixer.html#method.mnemonic
+pub struct Mixer {
+    mtype: MnemonicType,
+    mac: Hmac<Sha256>,
+    contributions: Vec<Contribution>,
+}
+
+impl Mixer {
+    /// Start mixing entropy for a mnemonic of `mtype`, with no sources yet
+    pub fn new(mtype: MnemonicType) -> Mixer {
+        Mixer {
+            mtype,
+            mac: Hmac::<Sha256>::new_varkey(MIXER_KEY).expect("HMAC accepts keys of any length"),
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Mix in entropy from the operating system's random number generator
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the operating system can't provide
+    /// it. Only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn system_rng(self) -> Result<Mixer, Error> {
+        let mut rng = match OsRng::new() {
+            Ok(rng) => rng,
+            Err(error) => Err(ErrorKind::RngFailure(error))?,
+        };
+
+        self.input_rng(&mut rng, Contribution::SystemRng)
+    }
+
+    /// Mix in entropy from `rng`, like a hardware RNG without the `std` feature
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the RNG fails.
+    pub fn rng<R: RngCore + CryptoRng + ?Sized>(self, rng: &mut R) -> Result<Mixer, Error> {
+        self.input_rng(rng, Contribution::Rng)
+    }
+
+    /// Mix in bytes from `rng`, recorded as `contribution`
+    fn input_rng<R: RngCore + CryptoRng + ?Sized>(mut self, rng: &mut R, contribution: Contribution) -> Result<Mixer, Error> {
+        let mut bytes = [0; RNG_BYTES];
+
+        if let Err(error) = rng.try_fill_bytes(&mut bytes) {
+            Err(ErrorKind::RngFailure(error))?;
+        }
+
+        self.input(0, &bytes, bytes.len() * 8);
+        self.contributions.push(contribution);
+
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+
+        Ok(self)
+    }
+
+    /// Mix in dice rolls, coin flips, digits or playing cards, written as for
+    /// [`Mnemonic::from_physical()`][Mnemonic::from_physical()]
+    ///
+    /// All of the input is used, not just as much as the mnemonic needs. Returns an `Error` of
+    /// kind `ErrorKind::InvalidEntropySymbol` or `ErrorKind::DuplicateCard` if it's malformed.
+    ///
+    /// [Mnemonic::from_physical()]: ../mnemonic/struct.Mnemonic.html#method.from_physical
+    pub fn physical(mut self, source: Source, input: &str) -> Result<Mixer, Error> {
+        // Every symbol takes at least one byte of input and gives at most five bits
+        let mut bits = vec![0; input.len()];
+        let extracted = source.extract(input, &mut bits, input.len() * 8)?;
+
+        self.input(1, &bits[..extracted.div_ceil(8)], extracted);
+        self.contributions.push(Contribution::Physical(source, extracted));
+
+        #[cfg(feature = "zeroize")]
+        bits.zeroize();
+
+        Ok(self)
+    }
+
+    /// Mix in `length` bytes read from the file at `path`, like the hardware RNG at `/dev/hwrng`
+    ///
+    /// Returns an `Error` if the file can't be read or ends early. Only available with the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    pub fn file<P: AsRef<Path>>(mut self, path: P, length: usize) -> Result<Mixer, Error> {
+        let mut bytes = vec![0; length];
+
+        File::open(path.as_ref())?.read_exact(&mut bytes)?;
+
+        self.input(2, &bytes, length * 8);
+        self.contributions.push(Contribution::File(path.as_ref().to_path_buf(), length));
+
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+
+        Ok(self)
+    }
+
+    /// Mix in arbitrary `bytes`, like keystroke timings or a photo of a lava lamp
+    ///
+    /// They can only make the result harder to guess, but they may well be public or
+    /// predictable, so they don't count towards the bits that
+    /// [`Mixer::mnemonic()`][Mixer::mnemonic()] requires.
+    ///
+    /// [Mixer::mnemonic()]: ./struct.Mixer.html#method.mnemonic
+    pub fn bytes(mut self, bytes: &[u8]) -> Mixer {
+        self.input(3, bytes, bytes.len() * 8);
+        self.contributions.push(Contribution::Bytes(bytes.len()));
+        self
+    }
+
+    /// Get the sources mixed in so far, in order
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    /// Create the [`Mnemonic`][Mnemonic] from everything that was mixed in
+    ///
+    /// Unless a random number generator was mixed in, physical sources and files have to add up to
+    /// at least as many bits as the mnemonic has entropy, bytes don't count. Returns an `Error` of
+    /// kind `ErrorKind::NotEnoughEntropy` with the number of bits they have and the number wanted
+    /// otherwise.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn mnemonic(self, lang: Language) -> Result<Mnemonic, Error> {
+        let wanted = self.mtype.entropy_bits();
+        let have = self.contributions.iter().map(Contribution::bits).sum();
+
+        if have < wanted {
+            Err(ErrorKind::NotEnoughEntropy(have, wanted))?;
+        }
+
+        let mut entropy = [0; 32];
+
+        entropy.copy_from_slice(&self.mac.result().code());
+
+        let mnemonic = Mnemonic::from_entropy(&entropy[..wanted / 8], lang);
+
+        #[cfg(feature = "zeroize")]
+        entropy.zeroize();
+
+        mnemonic
+    }
+
+    /// Feed one source to the HMAC, after a byte for its kind and its length in bits
+    fn input(&mut self, kind: u8, bytes: &[u8], bits: usize) {
+        self.mac.input(&[kind]);
+        self.mac.input(&(bits as u64).to_be_bytes());
+        self.mac.input(bytes);
+    }
+}
+
+/// Turn a `value` drawn uniformly from `0..base` into unbiased bits
+///
+/// `base` is split into powers of two, largest first, like 6 into 4 and 2. The group a value
+/// falls into says nothing about its position within the group, which is uniform, so that
+/// position gives as many bits as the size of the group has.
+fn split_uniform<F>(mut value: u32, mut base: u32, emit: &mut F)
+where
+    F: FnMut(u32, u32),
+{
+    while base > 1 {
+        let bits = 31 - base.leading_zeros();
+        let group = 1 << bits;
+
+        if value < group {
+            emit(value, bits);
+            return;
+        }
+
+        value -= group;
+        base -= group;
+    }
+}
+
+/// Get the value of a card from 0 to 51, ordered by rank and then by suit
+fn parse_card(card: &str) -> Option<u32> {
+    let (rank, suit) = match card.strip_prefix("10") {
+        Some(suit) => ('T', suit),
+        None => {
+            let mut chars = card.chars();
+
+            (chars.next()?, chars.as_str())
+        }
+    };
+
+    let mut suit = suit.chars();
+    let (rank, suit) = match (suit.next(), suit.next()) {
+        (Some(suit), None) => (rank.to_ascii_uppercase(), suit.to_ascii_uppercase()),
+        _ => return None,
+    };
+
+    Some(RANKS.find(rank)? as u32 * 4 + SUITS.find(suit)? as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn split_uniform_is_unbiased() {
+        for base in 2..=DECK_SIZE {
+            // how often each value of each length of output comes up, over all inputs
+            let mut counts = [[0u32; 64]; 6];
+
+            for value in 0..base {
+                split_uniform(value, base, &mut |bits, count| counts[count as usize][bits as usize] += 1);
+            }
+
+            for (count, outputs) in counts.iter().enumerate() {
+                let outputs = &outputs[..1 << count];
+
+                assert!(outputs.iter().all(|&n| n == outputs[0]), "{} is biased for {} bits", base, count);
+            }
+        }
+    }
+
+    #[test]
+    fn cards() {
+        assert_eq!(parse_card("AC"), Some(0));
+        assert_eq!(parse_card("as"), Some(3));
+        assert_eq!(parse_card("10H"), parse_card("TH"));
+        assert_eq!(parse_card("KS"), Some(51));
+        assert_eq!(parse_card("1H"), None);
+        assert_eq!(parse_card("A"), None);
+        assert_eq!(parse_card("ASS"), None);
+        assert_eq!(parse_card("é"), None);
+
+        // The last card of a full deck gives no bits
+        let deck: Vec<String> = RANKS.chars().flat_map(|rank| SUITS.chars().map(move |suit| format!("{}{}", rank, suit))).collect();
+        let mut output = [0; 32];
+
+        let full = Source::Cards.extract(&deck.join(" "), &mut output, 256).unwrap();
+        let all_but_last = Source::Cards.extract(&deck[..51].join(" "), &mut output, 256).unwrap();
+
+        assert_eq!(full, all_but_last);
+    }
+}
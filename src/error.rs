@@ -1,15 +1,101 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use language::Language;
 use mnemonic_type::MnemonicType;
 
-#[derive(Debug, Fail)]
+/// The error type of the crate
+///
+/// With the `std` feature (enabled by default) this is `failure::Error`, which an `ErrorKind` can
+/// be downcast from. Without it, errors are returned as the `ErrorKind` itself.
+#[cfg(feature = "std")]
+pub type Error = ::failure::Error;
+
+/// The error type of the crate
+///
+/// With the `std` feature (enabled by default) this is `failure::Error`, which an `ErrorKind` can
+/// be downcast from. Without it, errors are returned as the `ErrorKind` itself.
+#[cfg(not(feature = "std"))]
+pub type Error = ErrorKind;
+
+#[derive(Debug)]
 pub enum ErrorKind {
-	#[fail(display = "invalid checksum")]
 	InvalidChecksum,
-	#[fail(display = "invalid word in phrase")]
-	InvalidWord,
-	#[fail(display = "invalid keysize: {}", _0)]
+	InvalidWord(usize),
+	AmbiguousWord(usize),
+	AmbiguousLanguage(Vec<Language>),
 	InvalidKeysize(usize),
-	#[fail(display = "invalid number of words in phrase: {}", _0)]
 	InvalidWordLength(usize),
-	#[fail(display = "invalid entropy length {}bits for mnemonic type {:?}", _0, _1)]
 	InvalidEntropyLength(usize, MnemonicType),
-}
\ No newline at end of file
+	InvalidSeedLength(usize),
+	InvalidExtendedKey,
+	InvalidDerivedKey,
+	InvalidChildNumber(u32),
+	InvalidDerivationPath(String),
+	HardenedDerivationFromPublicKey,
+	HardenedDerivationRequired,
+	InvalidApplicationLength(usize),
+	InvalidSharePadding,
+	InvalidShareDigest,
+	MismatchedShares,
+	NotEnoughShares,
+	InvalidThreshold(usize, usize),
+	InvalidMasterSecretLength(usize),
+	InvalidIterationExponent(u8),
+	InvalidPassphrase,
+	InvalidSeedVersion,
+	InvalidWordlistLength(usize),
+	InvalidWordlistWord(usize),
+	DuplicateWord(usize, usize),
+	DuplicatePrefix(usize, usize),
+	CustomLanguage,
+	TooManyUnknownWords(usize),
+	RngFailure(::rand::Error),
+	InvalidEntropySymbol(usize),
+	DuplicateCard(usize),
+	NotEnoughEntropy(usize, usize),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::InvalidChecksum => f.write_str("invalid checksum"),
+            ErrorKind::InvalidWord(index) => write!(f, "invalid word in phrase at index {}", index),
+            ErrorKind::AmbiguousWord(index) => write!(f, "abbreviated word in phrase at index {} matches more than one word", index),
+            ErrorKind::AmbiguousLanguage(ref languages) => write!(f, "phrase is valid in more than one language: {:?}", languages),
+            ErrorKind::InvalidKeysize(size) => write!(f, "invalid keysize: {}", size),
+            ErrorKind::InvalidWordLength(count) => write!(f, "invalid number of words in phrase: {}", count),
+            ErrorKind::InvalidEntropyLength(bits, mtype) => write!(f, "invalid entropy length {}bits for mnemonic type {:?}", bits, mtype),
+            ErrorKind::InvalidSeedLength(length) => write!(f, "invalid seed length: {} bytes", length),
+            ErrorKind::InvalidExtendedKey => f.write_str("invalid extended key"),
+            ErrorKind::InvalidDerivedKey => f.write_str("derived key is invalid, proceed with the next index"),
+            ErrorKind::InvalidChildNumber(index) => write!(f, "invalid child number: {}", index),
+            ErrorKind::InvalidDerivationPath(ref path) => write!(f, "invalid derivation path: {}", path),
+            ErrorKind::HardenedDerivationFromPublicKey => f.write_str("cannot derive a hardened child from a public key"),
+            ErrorKind::HardenedDerivationRequired => f.write_str("only hardened children can be derived on this curve"),
+            ErrorKind::InvalidApplicationLength(length) => write!(f, "invalid length for BIP0085 application: {}", length),
+            ErrorKind::InvalidSharePadding => f.write_str("invalid padding in share"),
+            ErrorKind::InvalidShareDigest => f.write_str("share digest does not match, shares are corrupted or belong to different secrets"),
+            ErrorKind::MismatchedShares => f.write_str("shares do not belong to the same secret"),
+            ErrorKind::NotEnoughShares => f.write_str("not enough shares to recover the secret"),
+            ErrorKind::InvalidThreshold(threshold, count) => write!(f, "invalid threshold: {} of {}", threshold, count),
+            ErrorKind::InvalidMasterSecretLength(length) => write!(f, "invalid master secret length: {} bytes", length),
+            ErrorKind::InvalidIterationExponent(exponent) => write!(f, "invalid iteration exponent: {}", exponent),
+            ErrorKind::InvalidPassphrase => f.write_str("passphrase may only contain printable ASCII characters"),
+            ErrorKind::InvalidSeedVersion => f.write_str("phrase is not an Electrum seed of a known version"),
+            ErrorKind::InvalidWordlistLength(length) => write!(f, "invalid word list length: {}", length),
+            ErrorKind::InvalidWordlistWord(index) => write!(f, "word {} of word list is empty, too long or contains whitespace", index),
+            ErrorKind::DuplicateWord(first, second) => write!(f, "words {} and {} of word list are the same", first, second),
+            ErrorKind::DuplicatePrefix(first, second) => write!(f, "words {} and {} of word list start with the same four characters", first, second),
+            ErrorKind::CustomLanguage => f.write_str("custom word lists have no BIP0085 language code"),
+            ErrorKind::TooManyUnknownWords(count) => write!(f, "too many unknown words to recover: {}", count),
+            ErrorKind::RngFailure(ref error) => write!(f, "random number generator failed: {}", error),
+            ErrorKind::InvalidEntropySymbol(index) => write!(f, "invalid roll, flip, digit or card at index {}", index),
+            ErrorKind::DuplicateCard(index) => write!(f, "card at index {} was already drawn from the same deck", index),
+            ErrorKind::NotEnoughEntropy(bits, wanted) => write!(f, "not enough entropy: {} of {} bits", bits, wanted),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::failure::Fail for ErrorKind {}
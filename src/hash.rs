@@ -0,0 +1,39 @@
+// The word list hashing shared by build.rs, which generates the tables of the built-in lists, and
+// src/language.rs, which builds them for custom lists and looks words up. Both `include!` this
+// file, so it isn't a module of its own and can only use the prelude and `vec!`.
+
+/// The length in bytes of the longest word a list may have, the NFKD form of a four syllable
+/// Korean word. Phrases of up to 24 of these fit in the fixed-size buffer of a `Mnemonic`.
+pub(crate) const MAX_WORD_BYTES: usize = 36;
+
+// An empty slot of the hash table
+const EMPTY: u16 = u16::MAX;
+
+/// Build the hash table of a word list, twice its length so that probes stay short
+///
+/// At this load a hit takes 1.4 to 1.6 probes on average for the built-in lists, each comparing one
+/// word, so there is little left for a perfect hash to save. A perfect hash would also need a
+/// search for its displacements every time a custom list is loaded. A binary search over the sorted
+/// indices needs 11 string comparisons for a list of 2048 words, and made `validate` three times
+/// slower.
+fn hash_table<S: AsRef<str>>(words: &[S]) -> Vec<u16> {
+    let mut table = vec![EMPTY; 2 * words.len()];
+    let mask = table.len() - 1;
+
+    for (i, word) in words.iter().enumerate() {
+        let mut slot = hash_word(word.as_ref()) as usize & mask;
+
+        while table[slot] != EMPTY {
+            slot = (slot + 1) & mask;
+        }
+
+        table[slot] = i as u16;
+    }
+
+    table
+}
+
+/// 32-bit FNV-1a
+fn hash_word(word: &str) -> u32 {
+    word.bytes().fold(0x811c_9dc5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x0100_0193))
+}
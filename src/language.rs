@@ -1,92 +1,347 @@
-use hashbrown::HashMap;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::ptr;
 use error::ErrorKind;
-use failure::Error;
-use util::{Bits11, Bits};
+use error::Error;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::path::Path;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use util::{normalize_utf8, Bits11, Bits};
 
-pub struct WordMap {
-    inner: HashMap<&'static str, Bits11>
+// `MAX_WORD_BYTES`, `hash_table` and `hash_word`, shared with build.rs
+include!("hash.rs");
 
+/// The 2048 words of a [`Language`][Language], in the order that gives each word its value
+///
+/// Get one with [`Language::wordlist()`][Language::wordlist()]. Words are in Unicode NFKD form,
+/// like phrases are once normalized.
+///
+/// A list of your own can be loaded with [`WordList::from_words()`][WordList::from_words()] or
+/// [`WordList::from_file()`][WordList::from_file()], and used like a built-in one once it is
+/// turned into a [`Language`][Language] with [`Language::custom()`][Language::custom()].
+///
+/// [Language]: ./enum.Language.html
+/// [Language::wordlist()]: ./enum.Language.html#method.wordlist
+/// [Language::custom()]: ./enum.Language.html#method.custom
+/// [WordList::from_words()]: ./struct.WordList.html#method.from_words
+/// [WordList::from_file()]: ./struct.WordList.html#method.from_file
+pub struct WordList {
+    inner: Words,
+    // indices of `inner`, sorted by word
+    sorted: Cow<'static, [u16]>,
+    // indices of `inner` by hash of the word, with linear probing
+    table: Cow<'static, [u16]>,
 }
 
-pub struct WordList {
-    inner: Vec<&'static str>
+/// The words of a [`WordList`][WordList], generated by `build.rs` or loaded at runtime
+///
+/// [WordList]: ./struct.WordList.html
+enum Words {
+    Static(&'static [&'static str]),
+    Owned(Vec<String>),
 }
 
-impl WordMap {
-    pub fn get_bits(&self, word: &str) -> Result<Bits11, Error> {
-        match self.inner.get(word) {
-            Some(n) => Ok(*n),
-            None => Err(ErrorKind::InvalidWord)?
+impl WordList {
+    /// Create a word list from 2048 words, the first word having the value 0
+    ///
+    /// Words are normalized to Unicode NFKD. Returns an `Error` of kind
+    /// `ErrorKind::InvalidWordlistLength` if there aren't 2048 words,
+    /// `ErrorKind::InvalidWordlistWord` if a word is empty, longer than 36 bytes or contains
+    /// whitespace, and `ErrorKind::DuplicateWord` if a word appears twice.
+    ///
+    /// To use it in a [`Language`][Language] with [`Language::custom()`][Language::custom()], the
+    /// list has to be kept for as long as the language is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, WordList};
+    ///
+    /// let words: Vec<String> = Language::English.wordlist().iter().map(str::to_uppercase).collect();
+    /// let wordlist = WordList::from_words(&words).unwrap();
+    ///
+    /// assert_eq!(wordlist.index_of("ZOO"), Some(2047));
+    /// assert!(WordList::from_words(&words[1..]).is_err());
+    /// ```
+    ///
+    /// [Language]: ./enum.Language.html
+    /// [Language::custom()]: ./enum.Language.html#method.custom
+    pub fn from_words<I>(words: I) -> Result<WordList, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let words: Vec<String> = words.into_iter().map(|word| word.as_ref().nfkd().collect()).collect();
+
+        if words.len() != 2048 {
+            Err(ErrorKind::InvalidWordlistLength(words.len()))?;
+        }
+
+        if let Some(i) = words.iter().position(|word| word.is_empty() || word.len() > MAX_WORD_BYTES || word.contains(char::is_whitespace)) {
+            Err(ErrorKind::InvalidWordlistWord(i))?;
+        }
+
+        let mut sorted: Vec<usize> = (0..words.len()).collect();
+
+        sorted.sort_by_key(|&i| &words[i]);
+
+        if let Some(pair) = sorted.windows(2).find(|pair| words[pair[0]] == words[pair[1]]) {
+            Err(ErrorKind::DuplicateWord(pair[0].min(pair[1]), pair[0].max(pair[1])))?;
         }
+
+        let sorted: Vec<u16> = sorted.into_iter().map(|i| i as u16).collect();
+        let table = hash_table(&words);
+
+        Ok(WordList {
+            inner: Words::Owned(words),
+            sorted: Cow::Owned(sorted),
+            table: Cow::Owned(table),
+        })
     }
-}
 
-impl WordList {
-    pub fn get_word(&self, bits: Bits11) -> &'static str {
-        self.inner[bits.bits() as usize]
+    /// Read a word list from a UTF-8 text file, with one word per line
+    ///
+    /// The words are checked like in [`WordList::from_words()`][WordList::from_words()], and an
+    /// `Error` is also returned if the file can't be read.
+    ///
+    /// [WordList::from_words()]: ./struct.WordList.html#method.from_words
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<WordList, Error> {
+        let text = fs::read_to_string(path)?;
+
+        WordList::from_words(text.lines().map(str::trim).filter(|line| !line.is_empty()))
     }
-}
 
-mod lazy {
-    use super::{Bits11, WordList, WordMap};
-    use once_cell::sync::Lazy;
+    /// Check that no two words start with the same four characters
+    ///
+    /// All the built-in lists are chosen this way, so that a phrase can be written down with
+    /// only the start of each word and read back with
+    /// [`Mnemonic::from_abbreviated_phrase()`][Mnemonic::from_abbreviated_phrase()]. Accented
+    /// characters count as one. Returns an `Error` of kind `ErrorKind::DuplicatePrefix` with the
+    /// indices of two words that start the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, WordList};
+    ///
+    /// let mut words: Vec<&str> = Language::English.wordlist().iter().collect();
+    ///
+    /// words[1] = "abandoned";
+    ///
+    /// assert!(WordList::from_words(&words).unwrap().require_unique_prefixes().is_err());
+    /// ```
+    ///
+    /// [Mnemonic::from_abbreviated_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_abbreviated_phrase
+    pub fn require_unique_prefixes(self) -> Result<WordList, Error> {
+        let prefixes: Vec<String> = self.iter().map(|word| word.nfc().take(4).collect()).collect();
+        let mut sorted: Vec<usize> = (0..prefixes.len()).collect();
 
-    /// lazy generation of the word list
-    fn gen_wordlist(lang_words: &'static str) -> WordList {
-        let inner: Vec<_> = lang_words.split_whitespace().collect();
+        sorted.sort_by_key(|&i| &prefixes[i]);
 
-        debug_assert!(inner.len() == 2048, "Invalid wordlist length");
+        if let Some(pair) = sorted.windows(2).find(|pair| prefixes[pair[0]] == prefixes[pair[1]]) {
+            Err(ErrorKind::DuplicatePrefix(pair[0].min(pair[1]), pair[0].max(pair[1])))?;
+        }
+
+        Ok(self)
+    }
+
+    pub(crate) fn get_word(&self, bits: Bits11) -> &str {
+        self.word_at(bits.bits() as usize)
+    }
 
-        WordList {
-            inner
+    fn word_at(&self, i: usize) -> &str {
+        match self.inner {
+            Words::Static(words) => words[i],
+            Words::Owned(ref words) => &words[i],
         }
     }
 
-    /// lazy generation of the word map
-    fn gen_wordmap(wordlist: &WordList) -> WordMap {
-        let inner = wordlist.inner
-                            .iter()
-                            .enumerate()
-                            .map(|(i, item)| (*item, Bits11::from(i as u16)))
-                            .collect();
+    /// Iterate over the words in list order
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &str> {
+        (0..self.len()).map(move |i| self.word_at(i))
+    }
 
-        WordMap {
-            inner
+    /// Get the number of words, which is always 2048
+    pub fn len(&self) -> usize {
+        match self.inner {
+            Words::Static(words) => words.len(),
+            Words::Owned(ref words) => words.len(),
         }
     }
 
-    pub static WORDLIST_ENGLISH: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/english.txt")) };
-    #[cfg(feature = "chinese-simplified")]
-    pub static WORDLIST_CHINESE_SIMPLIFIED: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/chinese_simplified.txt")) };
-    #[cfg(feature = "chinese-traditional")]
-    pub static WORDLIST_CHINESE_TRADITIONAL: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/chinese_traditional.txt")) };
-    #[cfg(feature = "french")]
-    pub static WORDLIST_FRENCH: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/french.txt")) };
-    #[cfg(feature = "italian")]
-    pub static WORDLIST_ITALIAN: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/italian.txt")) };
-    #[cfg(feature = "japanese")]
-    pub static WORDLIST_JAPANESE: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/japanese.txt")) };
-    #[cfg(feature = "korean")]
-    pub static WORDLIST_KOREAN: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/korean.txt")) };
-    #[cfg(feature = "spanish")]
-    pub static WORDLIST_SPANISH: Lazy<WordList> = sync_lazy!{ gen_wordlist(include_str!("langs/spanish.txt")) };
+    /// Always `false`, every word list has 2048 words
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the index of a word in the list, which is its 11-bit value in a phrase
+    ///
+    /// The word is normalized to Unicode NFKD first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let wordlist = Language::English.wordlist();
+    ///
+    /// assert_eq!(wordlist.index_of("zoo"), Some(2047));
+    /// assert_eq!(wordlist.index_of("bitcoin"), None);
+    /// ```
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        self.get_bits(&normalize_utf8(word)).map(u16::from)
+    }
+
+    /// Get the word at an index of the list, the inverse of
+    /// [`WordList::index_of()`][WordList::index_of()]
+    ///
+    /// Returns `None` if the index is 2048 or more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let wordlist = Language::English.wordlist();
+    ///
+    /// assert_eq!(wordlist.word(2047), Some("zoo"));
+    /// assert_eq!(wordlist.word(2048), None);
+    /// ```
+    ///
+    /// [WordList::index_of()]: ./struct.WordList.html#method.index_of
+    pub fn word(&self, index: u16) -> Option<&str> {
+        if (index as usize) < self.len() {
+            Some(self.word_at(index as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Get the bits of a word that is already normalized
+    pub(crate) fn get_bits(&self, word: &str) -> Option<Bits11> {
+        let mask = self.table.len() - 1;
+        let mut slot = hash_word(word) as usize & mask;
+
+        loop {
+            match self.table[slot] {
+                EMPTY => return None,
+                i if self.word_at(i as usize) == word => return Some(Bits11::from(i)),
+                _ => slot = (slot + 1) & mask,
+            }
+        }
+    }
+
+    /// Iterate over all the words starting with `prefix`, in sorted order, for autocompletion
+    ///
+    /// Like with [`WordList::index_of()`][WordList::index_of()], the prefix is normalized to
+    /// Unicode NFKD first, so composed input from a keyboard matches. An accented character in
+    /// French or Spanish, or a Hangul syllable in Korean, then stands for every word that starts
+    /// with it, even when the word goes on with a final consonant of the same syllable. No
+    /// allocations take place unless the prefix has to be normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let words: Vec<&str> = Language::English.wordlist().starting_with("abs").collect();
+    ///
+    /// assert_eq!(words, ["absent", "absorb", "abstract", "absurd"]);
+    /// ```
+    ///
+    /// [WordList::index_of()]: ./struct.WordList.html#method.index_of
+    pub fn starting_with(&self, prefix: &str) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.starting_with_normalized(&normalize_utf8(prefix))
+    }
+
+    /// Iterate over the words starting with a `prefix` that is already normalized
+    fn starting_with_normalized(&self, prefix: &str) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        let start = self.sorted.partition_point(|&i| self.word_at(i as usize) < prefix);
+        let len = self.sorted[start..].partition_point(|&i| self.word_at(i as usize).starts_with(prefix));
+
+        self.sorted[start..start + len].iter().map(move |&i| self.word_at(i as usize))
+    }
+
+    /// Get the bits of the word that `prefix` abbreviates
+    ///
+    /// A word that matches `prefix` exactly is always chosen, otherwise it must be the start of
+    /// exactly one word, ending on a whole character of that word. The prefix is normalized to
+    /// Unicode NFKD first. Returns `None` if it starts no word, or an `Error` of kind
+    /// `ErrorKind::AmbiguousWord` with `index`, the position of the word in its phrase, if it is
+    /// the start of more than one.
+    pub(crate) fn get_bits_by_prefix(&self, prefix: &str, index: usize) -> Result<Option<Bits11>, Error> {
+        let prefix = normalize_utf8(prefix);
+
+        if let Some(bits) = self.get_bits(&prefix) {
+            return Ok(Some(bits));
+        }
+
+        let mut found = self.starting_with_normalized(&prefix).filter(|word| starts_character(&word[prefix.len()..]));
+
+        match (found.next(), found.next()) {
+            (Some(word), None) => Ok(Some(self.get_bits(word).expect("word is in the list"))),
+            (Some(_), Some(_)) => Err(ErrorKind::AmbiguousWord(index))?,
+            (None, _) => Ok(None),
+        }
+    }
+}
+
+impl fmt::Debug for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WordList").finish_non_exhaustive()
+    }
+}
+
+/// Whether the (NFKD) `rest` of a word starts a new character, rather than continuing the last
+/// one with an accent or, for Hangul, a vowel or final consonant jamo
+fn starts_character(rest: &str) -> bool {
+    match rest.chars().next() {
+        Some(c) => !is_combining_mark(c) && !('\u{1160}'..='\u{11FF}').contains(&c),
+        None => true,
+    }
+}
+
+mod lists {
+    use super::{WordList, Words};
+    use alloc::borrow::Cow;
+    use wordlists::*;
+
+    macro_rules! wordlist {
+        ($words:ident, $sorted:ident, $table:ident) => {
+            WordList {
+                inner: Words::Static(&$words),
+                sorted: Cow::Borrowed(&$sorted),
+                table: Cow::Borrowed(&$table),
+            }
+        };
+    }
 
-    pub static WORDMAP_ENGLISH: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_ENGLISH) };
+    pub static WORDLIST_ENGLISH: WordList = wordlist!(ENGLISH, ENGLISH_SORTED, ENGLISH_TABLE);
     #[cfg(feature = "chinese-simplified")]
-    pub static WORDMAP_CHINESE_SIMPLIFIED: Lazy<WordMap> = sync_lazy!{  gen_wordmap(&WORDLIST_CHINESE_SIMPLIFIED) };
+    pub static WORDLIST_CHINESE_SIMPLIFIED: WordList = wordlist!(CHINESE_SIMPLIFIED, CHINESE_SIMPLIFIED_SORTED, CHINESE_SIMPLIFIED_TABLE);
     #[cfg(feature = "chinese-traditional")]
-    pub static WORDMAP_CHINESE_TRADITIONAL: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_CHINESE_TRADITIONAL) };
+    pub static WORDLIST_CHINESE_TRADITIONAL: WordList = wordlist!(CHINESE_TRADITIONAL, CHINESE_TRADITIONAL_SORTED, CHINESE_TRADITIONAL_TABLE);
     #[cfg(feature = "french")]
-    pub static WORDMAP_FRENCH: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_FRENCH) };
+    pub static WORDLIST_FRENCH: WordList = wordlist!(FRENCH, FRENCH_SORTED, FRENCH_TABLE);
     #[cfg(feature = "italian")]
-    pub static WORDMAP_ITALIAN: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_ITALIAN) };
+    pub static WORDLIST_ITALIAN: WordList = wordlist!(ITALIAN, ITALIAN_SORTED, ITALIAN_TABLE);
     #[cfg(feature = "japanese")]
-    pub static WORDMAP_JAPANESE: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_JAPANESE) };
+    pub static WORDLIST_JAPANESE: WordList = wordlist!(JAPANESE, JAPANESE_SORTED, JAPANESE_TABLE);
     #[cfg(feature = "korean")]
-    pub static WORDMAP_KOREAN: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_KOREAN) };
+    pub static WORDLIST_KOREAN: WordList = wordlist!(KOREAN, KOREAN_SORTED, KOREAN_TABLE);
     #[cfg(feature = "spanish")]
-    pub static WORDMAP_SPANISH: Lazy<WordMap> = sync_lazy!{ gen_wordmap(&WORDLIST_SPANISH) };
-
+    pub static WORDLIST_SPANISH: WordList = wordlist!(SPANISH, SPANISH_SORTED, SPANISH_TABLE);
+    #[cfg(feature = "czech")]
+    pub static WORDLIST_CZECH: WordList = wordlist!(CZECH, CZECH_SORTED, CZECH_TABLE);
+    #[cfg(feature = "portuguese")]
+    pub static WORDLIST_PORTUGUESE: WordList = wordlist!(PORTUGUESE, PORTUGUESE_SORTED, PORTUGUESE_TABLE);
 }
 
 /// The language determines which words will be used in a mnemonic phrase, but also indirectly
@@ -95,10 +350,26 @@ mod lazy {
 /// These are not of much use right now, and may even be removed from the crate, as there is no
 /// official language specified by the standard except English.
 ///
+/// Besides the built-in languages, a word list loaded at runtime can be used with
+/// [`Language::custom()`][Language::custom()]:
+///
+/// ```no_run
+/// use bip39::{Language, WordList};
+/// use std::sync::OnceLock;
+///
+/// static WORDS: OnceLock<WordList> = OnceLock::new();
+///
+/// let lang = Language::custom(WORDS.get_or_init(|| {
+///     WordList::from_file("words.txt").unwrap().require_unique_prefixes().unwrap()
+/// }));
+/// ```
+///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
 /// [Seed]: ./seed/struct.Seed.html
-#[derive(Debug, Clone, Copy)]
+/// [Language::custom()]: ./enum.Language.html#method.custom
+#[derive(Debug, Clone, Copy, Default)]
 pub enum Language {
+    #[default]
     English,
     #[cfg(feature = "chinese-simplified")]
     ChineseSimplified,
@@ -114,57 +385,154 @@ pub enum Language {
     Korean,
     #[cfg(feature = "spanish")]
     Spanish,
+    #[cfg(feature = "czech")]
+    Czech,
+    #[cfg(feature = "portuguese")]
+    Portuguese,
+    /// A word list loaded at runtime, see [`Language::custom()`][Language::custom()]
+    ///
+    /// [Language::custom()]: ./enum.Language.html#method.custom
+    Custom(&'static WordList),
 }
 
+impl PartialEq for Language {
+    fn eq(&self, other: &Language) -> bool {
+        match (*self, *other) {
+            // Custom languages are told apart by the list they use, not by its words
+            (Language::Custom(a), Language::Custom(b)) => ptr::eq(a, b),
+            (a, b) => mem::discriminant(&a) == mem::discriminant(&b),
+        }
+    }
+}
+
+impl Eq for Language {}
+
 impl Language {
-    /// Get the word list for this language
-    pub fn wordlist(&self) -> &'static WordList {
-        match *self {
-            Language::English => &lazy::WORDLIST_ENGLISH,
+    /// Use a word list loaded at runtime like a built-in language
+    ///
+    /// Phrases use a regular space between words. The list has to outlive every `Language`
+    /// made from it, so it is kept wherever suits the caller, like a `static` `OnceLock` or a
+    /// `Box` leaked once at startup, after any checks like
+    /// [`WordList::require_unique_prefixes()`][WordList::require_unique_prefixes()]. Two custom
+    /// languages are only equal when they use the same list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, MnemonicType, WordList};
+    ///
+    /// let words: Vec<String> = (0..2048).map(|i| format!("word{}", i)).collect();
+    /// let wordlist: &'static WordList = Box::leak(Box::new(WordList::from_words(&words).unwrap()));
+    /// let lang = Language::custom(wordlist);
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words12, lang);
+    ///
+    /// assert!(mnemonic.phrase().starts_with("word"));
+    /// assert!(Mnemonic::validate(&mnemonic.phrase(), lang).is_ok());
+    /// ```
+    ///
+    /// [WordList::require_unique_prefixes()]: ./struct.WordList.html#method.require_unique_prefixes
+    pub fn custom(wordlist: &'static WordList) -> Language {
+        Language::Custom(wordlist)
+    }
+
+    /// Get every language enabled by the crate features, English first
+    ///
+    /// Custom languages aren't included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::all()[0], Language::English);
+    /// ```
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::English,
             #[cfg(feature = "chinese-simplified")]
-            Language::ChineseSimplified => &lazy::WORDLIST_CHINESE_SIMPLIFIED,
+            Language::ChineseSimplified,
             #[cfg(feature = "chinese-traditional")]
-            Language::ChineseTraditional => &lazy::WORDLIST_CHINESE_TRADITIONAL,
+            Language::ChineseTraditional,
             #[cfg(feature = "french")]
-            Language::French => &lazy::WORDLIST_FRENCH,
+            Language::French,
             #[cfg(feature = "italian")]
-            Language::Italian => &lazy::WORDLIST_ITALIAN,
+            Language::Italian,
             #[cfg(feature = "japanese")]
-            Language::Japanese => &lazy::WORDLIST_JAPANESE,
+            Language::Japanese,
             #[cfg(feature = "korean")]
-            Language::Korean => &lazy::WORDLIST_KOREAN,
+            Language::Korean,
             #[cfg(feature = "spanish")]
-            Language::Spanish => &lazy::WORDLIST_SPANISH,
-        }
+            Language::Spanish,
+            #[cfg(feature = "czech")]
+            Language::Czech,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese,
+        ]
     }
 
-    /// Get a [`WordMap`][WordMap] that allows word -> index lookups in the word list
-    ///
-    /// The index of an individual word in the word list is used as the binary value of that word
-    /// when the phrase is turned into a [`Seed`][Seed].
-    pub fn wordmap(&self) -> &'static WordMap {
+    /// Get the word list for this language
+    pub fn wordlist(&self) -> &'static WordList {
         match *self {
-            Language::English => &lazy::WORDMAP_ENGLISH,
+            Language::English => &lists::WORDLIST_ENGLISH,
             #[cfg(feature = "chinese-simplified")]
-            Language::ChineseSimplified => &lazy::WORDMAP_CHINESE_SIMPLIFIED,
+            Language::ChineseSimplified => &lists::WORDLIST_CHINESE_SIMPLIFIED,
             #[cfg(feature = "chinese-traditional")]
-            Language::ChineseTraditional => &lazy::WORDMAP_CHINESE_TRADITIONAL,
+            Language::ChineseTraditional => &lists::WORDLIST_CHINESE_TRADITIONAL,
             #[cfg(feature = "french")]
-            Language::French => &lazy::WORDMAP_FRENCH,
+            Language::French => &lists::WORDLIST_FRENCH,
             #[cfg(feature = "italian")]
-            Language::Italian => &lazy::WORDMAP_ITALIAN,
+            Language::Italian => &lists::WORDLIST_ITALIAN,
             #[cfg(feature = "japanese")]
-            Language::Japanese => &lazy::WORDMAP_JAPANESE,
+            Language::Japanese => &lists::WORDLIST_JAPANESE,
             #[cfg(feature = "korean")]
-            Language::Korean => &lazy::WORDMAP_KOREAN,
+            Language::Korean => &lists::WORDLIST_KOREAN,
             #[cfg(feature = "spanish")]
-            Language::Spanish => &lazy::WORDMAP_SPANISH,
+            Language::Spanish => &lists::WORDLIST_SPANISH,
+            #[cfg(feature = "czech")]
+            Language::Czech => &lists::WORDLIST_CZECH,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => &lists::WORDLIST_PORTUGUESE,
+            Language::Custom(wordlist) => wordlist,
+        }
+    }
+
+    /// Get the separator placed between words when a phrase is created in this language
+    ///
+    /// This is the ideographic space (`U+3000`) for Japanese, and a regular space for every other
+    /// language. When parsing a phrase any run of Unicode whitespace is accepted between words,
+    /// regardless of the language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.separator(), " ");
+    /// ```
+    pub fn separator(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "\u{3000}",
+            _ => " ",
         }
     }
 }
 
-impl Default for Language {
-    fn default() -> Language {
-        Language::English
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "french")]
+    #[test]
+    fn prefix_normalization() {
+        let wordlist = Language::French.wordlist();
+        let eleve = wordlist.index_of("\u{e9}l\u{e8}ve").unwrap();
+
+        // Composed accents, as typed on most keyboards, are normalized like whole words
+        assert_eq!(wordlist.get_bits_by_prefix("\u{e9}l\u{e8}", 0).unwrap().map(u16::from), Some(eleve));
+        assert_eq!(wordlist.get_bits_by_prefix("e\u{301}le\u{300}", 0).unwrap().map(u16::from), Some(eleve));
+        assert_eq!(wordlist.get_bits_by_prefix("\u{e9}l\u{e8}ve", 0).unwrap().map(u16::from), Some(eleve));
+        assert!(wordlist.get_bits_by_prefix("\u{e9}l", 0).is_err());
     }
 }
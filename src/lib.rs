@@ -14,7 +14,7 @@
 //! let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
 //!
 //! /// get the phrase
-//! let phrase: &str = mnemonic.phrase();
+//! let phrase = mnemonic.phrase();
 //! println!("phrase: {}", phrase);
 //!
 //! /// get the HD wallet seed
@@ -27,12 +27,46 @@
 //! println!("{:X}", seed);
 //! ```
 //!
-#[macro_use] extern crate failure;
-#[macro_use] extern crate once_cell;
+//! ## `no_std`
+//!
+//! Without the `std` feature (enabled by default) the crate only needs `alloc`. Generating a
+//! mnemonic then takes a random number generator with
+//! [`Mnemonic::new_with_rng()`][Mnemonic::new_with_rng()], and errors are returned as
+//! [`ErrorKind`][ErrorKind] values instead of `failure::Error`. Key derivation, SLIP-0039 shares
+//! and Electrum seeds still need `std`.
+//!
+//! [Mnemonic::new_with_rng()]: ./mnemonic/struct.Mnemonic.html#method.new_with_rng
+//! [ErrorKind]: ./enum.ErrorKind.html
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "std")]
+extern crate failure;
+#[cfg(feature = "slip39")]
 extern crate pbkdf2;
-extern crate hashbrown;
+extern crate rand;
 extern crate sha2;
 extern crate hmac;
+extern crate unicode_normalization;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+#[cfg(feature = "bip32")]
+extern crate k256;
+#[cfg(feature = "bip32")]
+extern crate ripemd160;
+#[cfg(feature = "bip32")]
+extern crate bs58;
+#[cfg(feature = "slip10")]
+extern crate ed25519_dalek;
+#[cfg(feature = "slip10")]
+extern crate p256;
 
 mod mnemonic;
 mod error;
@@ -40,11 +74,29 @@ mod mnemonic_type;
 mod language;
 mod util;
 mod seed;
+pub mod recovery;
+pub mod entropy;
+#[cfg(feature = "bip32")]
+pub mod bip32;
+#[cfg(feature = "slip10")]
+pub mod slip10;
+#[cfg(feature = "bip85")]
+pub mod bip85;
+#[cfg(feature = "slip39")]
+pub mod slip39;
+#[cfg(feature = "electrum")]
+pub mod electrum;
 
 mod crypto;
 
-pub use language::Language;
-pub use mnemonic::Mnemonic;
+/// The word lists as static arrays, generated by `build.rs`
+mod wordlists {
+    include!(concat!(env!("OUT_DIR"), "/wordlists.rs"));
+}
+
+pub use language::{Language, WordList};
+pub use mnemonic::{Mnemonic, Phrase, SideBySide};
 pub use mnemonic_type::MnemonicType;
 pub use seed::Seed;
-pub use error::ErrorKind;
+pub use error::{Error, ErrorKind};
+pub use rand::{CryptoRng, Error as RngError, ErrorKind as RngErrorKind, RngCore};
@@ -1,10 +1,23 @@
-use util::{checksum, IterExt, BitWriter};
-use crypto::{gen_random_bytes, sha256_first_byte};
+use util::{checksum, normalize_utf8, reserve_secret, IterExt, BitWriter, Bits11};
+use crypto::sha256_first_byte;
+#[cfg(feature = "std")]
+use crypto::os_rng;
+use entropy::Source;
 use error::ErrorKind;
-use failure::Error;
+use error::Error;
 use mnemonic_type::MnemonicType;
-use language::Language;
-use std::fmt;
+use language::{Language, MAX_WORD_BYTES};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+#[cfg(feature = "std")]
+use rand::thread_rng;
+use rand::{CryptoRng, RngCore};
+use unicode_normalization::UnicodeNormalization;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 /// The primary type in this crate, most tasks require creating or using one.
 ///
@@ -29,17 +42,35 @@ use std::fmt;
 /// [Seed::new()]: ./seed/struct.Seed.html#method.new
 /// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
 ///
+/// A [`Mnemonic`][Mnemonic] doesn't allocate. The entropy and the index of each word are kept in
+/// fixed-size arrays big enough for 24 words, and the phrase is rendered from the word indices
+/// when it is asked for, by [`Mnemonic::phrase()`][Mnemonic::phrase()] into a [`Phrase`][Phrase]
+/// on the stack. Code that only needs the words can use
+/// [`Mnemonic::words()`][Mnemonic::words()], which reads them from the list.
+///
+/// With the `zeroize` feature (enabled by default) the entropy and words are wiped from memory
+/// when a [`Mnemonic`][Mnemonic] is dropped, and so is a rendered [`Phrase`][Phrase].
+///
+/// [Mnemonic::phrase()]: ./mnemonic/struct.Mnemonic.html#method.phrase
+/// [Mnemonic::words()]: ./mnemonic/struct.Mnemonic.html#method.words
+/// [Phrase]: ./mnemonic/struct.Phrase.html
 #[derive(Clone)]
 pub struct Mnemonic {
-    phrase: String,
     lang: Language,
-    entropy: Vec<u8>,
+    mtype: MnemonicType,
+    // only the first `mtype.entropy_bits() / 8` bytes are used
+    entropy: [u8; 32],
+    // only the first `mtype.word_count()` words are used
+    words: [u16; 24],
 }
 
 impl Mnemonic {
     /// Generates a new [`Mnemonic`][Mnemonic]
     ///
-    /// Use [`Mnemonic::phrase()`][Mnemonic::phrase()] to get an `str` slice of the generated phrase.
+    /// Use [`Mnemonic::phrase()`][Mnemonic::phrase()] to render the generated phrase.
+    ///
+    /// The entropy comes from the thread local RNG, which panics if it can't be seeded by the
+    /// operating system. [`Mnemonic::try_new()`][Mnemonic::try_new()] returns an error instead.
     ///
     /// # Example
     ///
@@ -56,10 +87,96 @@ impl Mnemonic {
     ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
     /// [Mnemonic::phrase()]: ./mnemonic/struct.Mnemonic.html#method.phrase
+    /// [Mnemonic::try_new()]: ./mnemonic/struct.Mnemonic.html#method.try_new
+    #[cfg(feature = "std")]
     pub fn new(mtype: MnemonicType, lang: Language) -> Mnemonic {
-        let entropy = gen_random_bytes(mtype.entropy_bits() / 8);
+        Mnemonic::new_with_rng(mtype, lang, &mut thread_rng())
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] with entropy from the operating system
+    ///
+    /// Unlike [`Mnemonic::new()`][Mnemonic::new()], this returns an `Error` of kind
+    /// `ErrorKind::RngFailure` if the operating system RNG fails, rather than panicking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::try_new(MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase().split(" ").count(), 12);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
+    #[cfg(feature = "std")]
+    pub fn try_new(mtype: MnemonicType, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::try_new_with_rng(mtype, lang, &mut os_rng()?)
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] with entropy from `rng`
+    ///
+    /// This is how mnemonics are generated without the `std` feature, where there is no thread
+    /// local RNG to fall back on. The RNG has to be cryptographically secure, like a hardware
+    /// RNG; the [`RngCore`][RngCore] and [`CryptoRng`][CryptoRng] traits, along with
+    /// [`RngError`][RngError], are re-exported for implementing it. A seeded RNG makes for
+    /// reproducible phrases in tests.
+    ///
+    /// Panics if the RNG fails, use
+    /// [`Mnemonic::try_new_with_rng()`][Mnemonic::try_new_with_rng()] for one that can.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    /// # extern crate bip39;
+    ///
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// # fn main() {
+    /// let mnemonic = Mnemonic::new_with_rng(MnemonicType::Words24, Language::English, &mut rand::rngs::OsRng::new().unwrap());
+    ///
+    /// assert_eq!(mnemonic.phrase().split(" ").count(), 24);
+    /// # }
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [RngCore]: ./trait.RngCore.html
+    /// [CryptoRng]: ./trait.CryptoRng.html
+    /// [RngError]: ./struct.RngError.html
+    /// [Mnemonic::try_new_with_rng()]: ./mnemonic/struct.Mnemonic.html#method.try_new_with_rng
+    pub fn new_with_rng<R>(mtype: MnemonicType, lang: Language, rng: &mut R) -> Mnemonic
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let mut mnemonic = Mnemonic::empty(mtype, lang);
+
+        rng.fill_bytes(&mut mnemonic.entropy[..mtype.entropy_bits() / 8]);
+        mnemonic.encode();
 
-        Mnemonic::from_entropy_unchecked(entropy, lang)
+        mnemonic
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] with entropy from an `rng` that can fail, like an
+    /// HSM or a hardware RNG
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` with the error of the RNG if it fails.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn try_new_with_rng<R>(mtype: MnemonicType, lang: Language, rng: &mut R) -> Result<Mnemonic, Error>
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let mut mnemonic = Mnemonic::empty(mtype, lang);
+
+        if let Err(error) = rng.try_fill_bytes(&mut mnemonic.entropy[..mtype.entropy_bits() / 8]) {
+            Err(ErrorKind::RngFailure(error))?;
+        }
+
+        mnemonic.encode();
+
+        Ok(mnemonic)
     }
 
     /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy
@@ -79,39 +196,86 @@ impl Mnemonic {
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
     pub fn from_entropy(entropy: &[u8], lang: Language) -> Result<Mnemonic, Error> {
         // Validate entropy size
-        MnemonicType::for_key_size(entropy.len() * 8)?;
+        let mtype = MnemonicType::for_key_size(entropy.len() * 8)?;
+        let mut mnemonic = Mnemonic::empty(mtype, lang);
 
-        Ok(Self::from_entropy_unchecked(entropy, lang))
+        mnemonic.entropy[..entropy.len()].copy_from_slice(entropy);
+        mnemonic.encode();
+
+        Ok(mnemonic)
     }
 
-    fn from_entropy_unchecked<E>(entropy: E, lang: Language) -> Mnemonic
-    where
-        E: Into<Vec<u8>>
-    {
-        let entropy = entropy.into();
-        let wordlist = lang.wordlist();
+    /// Create a [`Mnemonic`][Mnemonic] from dice rolls, coin flips, digits or playing cards
+    ///
+    /// See the [`entropy`][entropy] module for how the input of each [`Source`][Source] is
+    /// written and turned into entropy. Returns an `Error` of kind `ErrorKind::NotEnoughEntropy`
+    /// if the input gives fewer bits than `mtype` needs, and
+    /// [`Source::needed()`][Source::needed()] tells how many more to add.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    /// use bip39::entropy::Source;
+    ///
+    /// let flips = "0110100110010110".repeat(8);
+    /// let mnemonic = Mnemonic::from_physical(Source::CoinFlips, &flips, MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!(format!("{:x}", mnemonic), "69966996699669966996699669966996");
+    /// assert!(Mnemonic::from_physical(Source::CoinFlips, &flips[1..], MnemonicType::Words12, Language::English).is_err());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [entropy]: ../entropy/index.html
+    /// [Source]: ../entropy/enum.Source.html
+    /// [Source::needed()]: ../entropy/enum.Source.html#method.needed
+    pub fn from_physical(source: Source, input: &str, mtype: MnemonicType, lang: Language) -> Result<Mnemonic, Error> {
+        let wanted = mtype.entropy_bits();
+        let mut mnemonic = Mnemonic::empty(mtype, lang);
+        let extracted = source.extract(input, &mut mnemonic.entropy, wanted)?;
+
+        if extracted < wanted {
+            Err(ErrorKind::NotEnoughEntropy(extracted, wanted))?;
+        }
+
+        mnemonic.encode();
+
+        Ok(mnemonic)
+    }
+
+    /// A [`Mnemonic`][Mnemonic] with the entropy and words still to be filled in
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    fn empty(mtype: MnemonicType, lang: Language) -> Mnemonic {
+        Mnemonic {
+            lang,
+            mtype,
+            entropy: [0; 32],
+            words: [0; 24],
+        }
+    }
 
-        let checksum_byte = sha256_first_byte(&entropy);
+    /// Work out the words from the entropy
+    fn encode(&mut self) {
+        let entropy = &self.entropy[..self.mtype.entropy_bits() / 8];
+        let checksum_byte = sha256_first_byte(entropy);
 
         // First, create a byte iterator for the given entropy and the first byte of the
         // hash of the entropy that will serve as the checksum (up to 8 bits for biggest
         // entropy source).
         //
         // Then we transform that into a bits iterator that returns 11 bits at a
-        // time (as u16), which we can map to the words on the `wordlist`.
+        // time (as u16), which are the indices of the words on the word list.
         //
         // Given the entropy is of correct size, this ought to give us the correct word
         // count.
-        let phrase = entropy.iter()
-                            .chain(Some(&checksum_byte))
-                            .bits()
-                            .map(|bits| wordlist.get_word(bits))
-                            .join(" ");
+        let indices = entropy.iter()
+                             .chain(Some(&checksum_byte))
+                             .bits()
+                             .map(|bits: Bits11| u16::from(bits));
 
-        Mnemonic {
-            phrase,
-            lang,
-            entropy
+        for (word, index) in self.words.iter_mut().zip(indices) {
+            *word = index;
         }
     }
 
@@ -120,6 +284,12 @@ impl Mnemonic {
     /// The phrase supplied will be checked for word length and validated according to the checksum
     /// specified in BIP0039
     ///
+    /// The phrase is normalized to Unicode NFKD first, so composed and decomposed forms of accented
+    /// or Hangul words are accepted alike. Words may be separated by any run of Unicode whitespace.
+    ///
+    /// [`Mnemonic::phrase()`][Mnemonic::phrase()] will return the phrase in its canonical form,
+    /// normalized and with the words joined by [`Language::separator()`][Language::separator()].
+    ///
     /// # Example
     ///
     /// ```
@@ -132,30 +302,159 @@ impl Mnemonic {
     /// ```
     ///
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::phrase()]: ../mnemonic/struct.Mnemonic.html#method.phrase
+    /// [Language::separator()]: ../language/enum.Language.html#method.separator
     pub fn from_phrase<S>(phrase: S, lang: Language) -> Result<Mnemonic, Error>
     where
         S: Into<String>,
     {
-        let phrase = phrase.into();
+        #[allow(unused_mut)]
+        let mut phrase = phrase.into();
 
-        // this also validates the checksum and phrase length before returning the entropy so we
-        // can store it. We don't use the validate function here to avoid having a public API that
-        // takes a phrase string and returns the entropy directly.
-        let entropy = Mnemonic::phrase_to_entropy(&phrase, lang)?;
+        // Only the word indices are kept, so the phrase is rendered with canonical whitespace and
+        // normalization
+        let mut mnemonic = Mnemonic::empty(MnemonicType::default(), lang);
+        let parsed = mnemonic.parse(&normalize_utf8(&phrase), false);
 
-        let mnemonic = Mnemonic {
-            phrase,
-            lang,
-            entropy,
+        #[cfg(feature = "zeroize")]
+        phrase.zeroize();
+
+        parsed?;
+
+        Ok(mnemonic)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase in any of the enabled languages
+    ///
+    /// The phrase is checked against the word list of every language in
+    /// [`Language::all()`][Language::all()], like in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    /// Some word lists share words, English and French have about a hundred in common and the
+    /// two Chinese lists over a thousand, so a phrase can be valid in more than one language.
+    /// Since each language gives it a different seed, that returns an `Error` of kind
+    /// `ErrorKind::AmbiguousLanguage` listing all of them, and the phrase has to be parsed again
+    /// with [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] and the right language.
+    ///
+    /// If the phrase isn't valid in any language, the error is the one from the language that
+    /// has the most of its words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase_any_language(phrase).unwrap();
+    ///
+    /// assert_eq!(mnemonic.language(), Language::English);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Language::all()]: ../language/enum.Language.html#method.all
+    pub fn from_phrase_any_language<S>(phrase: S) -> Result<Mnemonic, Error>
+    where
+        S: Into<String>,
+    {
+        #[allow(unused_mut)]
+        let mut phrase = phrase.into();
+        let normalized = normalize_utf8(&phrase);
+
+        let mut found: Option<Mnemonic> = None;
+        let mut languages = Vec::new();
+        let mut closest: Option<(usize, Error)> = None;
+
+        for &lang in Language::all() {
+            let mut mnemonic = Mnemonic::empty(MnemonicType::default(), lang);
+
+            match mnemonic.parse(&normalized, false) {
+                Ok(()) => {
+                    languages.push(lang);
+
+                    // Any further matches are dropped, which wipes them
+                    if found.is_none() {
+                        found = Some(mnemonic);
+                    }
+                }
+                Err(error) => {
+                    let wordlist = lang.wordlist();
+                    let known = normalized.split_whitespace().filter(|word| wordlist.get_bits(word).is_some()).count();
+
+                    let closer = match closest {
+                        Some((most, _)) => known > most,
+                        None => true,
+                    };
+
+                    if closer {
+                        closest = Some((known, error));
+                    }
+                }
+            }
+        }
+
+        drop(normalized);
+
+        #[cfg(feature = "zeroize")]
+        phrase.zeroize();
+
+        let mnemonic = match found {
+            Some(mnemonic) => mnemonic,
+            None => return Err(closest.expect("there is at least one language").1),
         };
 
+        if languages.len() > 1 {
+            Err(ErrorKind::AmbiguousLanguage(languages))?;
+        }
+
+        Ok(mnemonic)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase where words may be abbreviated
+    ///
+    /// Every word list is chosen so that its words are told apart by their first four characters,
+    /// which is often all that gets stamped on a metal backup. Each word of the phrase may be the
+    /// full word, or the start of exactly one word in the list. A start that matches more than one
+    /// word returns an `Error` of kind `ErrorKind::AmbiguousWord` with the position of the word.
+    ///
+    /// Otherwise this works like [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], and
+    /// [`Mnemonic::phrase()`][Mnemonic::phrase()] returns the full words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let abbreviated = "park rema pers kitc mule spel knee arme posi rail grid ankl";
+    /// let mnemonic = Mnemonic::from_abbreviated_phrase(abbreviated, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), "park remain person kitchen mule spell knee armed position rail grid ankle");
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Mnemonic::phrase()]: ../mnemonic/struct.Mnemonic.html#method.phrase
+    pub fn from_abbreviated_phrase<S>(phrase: S, lang: Language) -> Result<Mnemonic, Error>
+    where
+        S: Into<String>,
+    {
+        #[allow(unused_mut)]
+        let mut phrase = phrase.into();
+
+        let mut mnemonic = Mnemonic::empty(MnemonicType::default(), lang);
+        let parsed = mnemonic.parse(&normalize_utf8(&phrase), true);
+
+        #[cfg(feature = "zeroize")]
+        phrase.zeroize();
+
+        parsed?;
+
         Ok(mnemonic)
     }
 
     /// Validate a mnemonic phrase
     ///
     /// The phrase supplied will be checked for word length and validated according to the checksum
-    /// specified in BIP0039.
+    /// specified in BIP0039. Like [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], the phrase
+    /// is normalized to Unicode NFKD before validation.
     ///
     /// # Example
     ///
@@ -166,59 +465,171 @@ impl Mnemonic {
     ///
     /// assert!(Mnemonic::validate(test_mnemonic, Language::English).is_ok());
     /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
     pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
-        Mnemonic::phrase_to_entropy(phrase, lang)?;
-
-        Ok(())
+        Mnemonic::empty(MnemonicType::default(), lang).parse(&normalize_utf8(phrase), false)
     }
 
-    /// Calculate the checksum, verify it and return the entropy
+    /// Look up the words in the word list of the language, verify the checksum and fill in the
+    /// entropy, words and type
     ///
-    /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
-    /// used as the seed is likely to cause problems for someone eventually. All the other functions
-    /// that return something like that are explicit about what it is and what to use it for.
-    fn phrase_to_entropy(phrase: &str, lang: Language) -> Result<Vec<u8>, Error> {
-        let wordmap = lang.wordmap();
+    /// The words are stored as they are looked up, so on any error they are wiped when the
+    /// `Mnemonic` is dropped.
+    fn parse(&mut self, phrase: &str, abbreviated: bool) -> Result<(), Error> {
+        let wordlist = self.lang.wordlist();
+        let mut count = 0;
+
+        for (index, word) in phrase.split_whitespace().enumerate() {
+            let found = if abbreviated {
+                wordlist.get_bits_by_prefix(word, index)?
+            } else {
+                wordlist.get_bits(word)
+            };
 
-        // Preallocate enough space for the longest possible word list
-        let mut bits = BitWriter::with_capacity(264);
+            match found {
+                // Words past the longest phrase are only checked, the length is rejected below
+                Some(word_bits) => if let Some(slot) = self.words.get_mut(index) {
+                    *slot = word_bits.into();
+                },
+                None => Err(ErrorKind::InvalidWord(index))?,
+            }
 
-        for word in phrase.split(" ") {
-            bits.push(wordmap.get_bits(&word)?);
+            count += 1;
         }
 
-        let mtype = MnemonicType::for_word_count(bits.len() / 11)?;
+        let mtype = MnemonicType::for_word_count(count)?;
+        let entropy_bytes = mtype.entropy_bits() / 8;
+
+        self.mtype = mtype;
+
+        // The entropy followed by the checksum, up to 8 bits for the biggest entropy
+        let mut bytes = [0u8; 33];
+        let mut bits = BitWriter::new(&mut bytes);
+
+        for &word in &self.words[..count] {
+            bits.push(Bits11::from(word));
+        }
 
         debug_assert!(bits.len() == mtype.total_bits(), "Insufficient amount of bits to validate");
 
-        let mut entropy = bits.into_bytes();
-        let entropy_bytes = mtype.entropy_bits() / 8;
+        bits.finish();
 
-        let actual_checksum = checksum(entropy[entropy_bytes], mtype.checksum_bits());
+        let actual_checksum = checksum(bytes[entropy_bytes], mtype.checksum_bits());
 
-        // Truncate to get rid of the byte containing the checksum
-        entropy.truncate(entropy_bytes);
+        self.entropy[..entropy_bytes].copy_from_slice(&bytes[..entropy_bytes]);
 
-        let checksum_byte = sha256_first_byte(&entropy);
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+
+        let checksum_byte = sha256_first_byte(self.entropy());
         let expected_checksum = checksum(checksum_byte, mtype.checksum_bits());
 
         if actual_checksum != expected_checksum {
             Err(ErrorKind::InvalidChecksum)?;
         }
 
-        Ok(entropy)
+        Ok(())
+    }
+
+    /// Render the mnemonic phrase, with the separator of the language
+    ///
+    /// The [`Phrase`][Phrase] is returned on the stack and dereferences to a `str`. Each call
+    /// renders it again from the word indices, so keep it around rather than calling this in a
+    /// loop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase(), phrase);
+    /// assert_eq!(mnemonic.phrase().split(" ").count(), 12);
+    /// ```
+    ///
+    /// [Phrase]: ./struct.Phrase.html
+    pub fn phrase(&self) -> Phrase {
+        let mut phrase = Phrase::empty();
+
+        phrase.render(self.words(), self.lang.separator());
+
+        phrase
     }
 
-    /// Get the mnemonic phrase as a string reference.
-    pub fn phrase(&self) -> &str {
-        &self.phrase
+    /// Iterate over the words of the phrase
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().nth(3), Some("kitchen"));
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let wordlist = self.lang.wordlist();
+
+        self.words[..self.mtype.word_count()].iter().map(move |&word| wordlist.get_word(Bits11::from(word)))
+    }
+
+    /// Render the phrase with its words separated by regular spaces, which is its NFKD form as
+    /// the words of every list are normalized already
+    pub(crate) fn normalized_phrase(&self) -> Phrase {
+        let mut phrase = Phrase::empty();
+
+        phrase.render(self.words(), " ");
+
+        phrase
+    }
+
+    /// Get the phrase with every word shortened to its first four characters
+    ///
+    /// Words of four characters or less are kept whole, and accented characters count as one.
+    /// The result can be read back with
+    /// [`Mnemonic::from_abbreviated_phrase()`][Mnemonic::from_abbreviated_phrase()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.abbreviated_phrase(), "park rema pers kitc mule spel knee arme posi rail grid ankl");
+    /// ```
+    ///
+    /// [Mnemonic::from_abbreviated_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_abbreviated_phrase
+    pub fn abbreviated_phrase(&self) -> String {
+        let separator = self.lang.separator();
+        let mut abbreviated = String::new();
+
+        for (i, word) in self.words().enumerate() {
+            if i > 0 {
+                reserve_secret(&mut abbreviated, separator.len());
+                abbreviated.push_str(separator);
+            }
+
+            // Count composed characters, so that accents and Hangul jamo aren't cut off
+            for c in word.nfc().take(4).nfkd() {
+                reserve_secret(&mut abbreviated, c.len_utf8());
+                abbreviated.push(c);
+            }
+        }
+
+        abbreviated
     }
 
     /// Consume the `Mnemonic` and return the phrase as a `String`.
     ///
-    /// This operation doesn't perform any allocations.
+    /// The returned phrase is no longer wiped from memory when dropped.
     pub fn into_phrase(self) -> String {
-        self.phrase
+        self.phrase().to_string()
     }
 
     /// Get the original entropy value of the mnemonic phrase as a slice.
@@ -238,7 +649,7 @@ impl Mnemonic {
     /// **Note:** You shouldn't use the generated entropy as secrets, for that generate a new
     /// `Seed` from the `Mnemonic`.
     pub fn entropy(&self) -> &[u8] {
-        &self.entropy
+        &self.entropy[..self.mtype.entropy_bits() / 8]
     }
 
     /// Get the [`Language`][Language]
@@ -247,23 +658,271 @@ impl Mnemonic {
     pub fn language(&self) -> Language {
         self.lang
     }
+
+    /// Encode the same entropy with the word list of another [`Language`][Language]
+    ///
+    /// Each word is replaced with the word at the same index in the other list, so the phrase
+    /// stands for the same entropy and can be translated back.
+    ///
+    /// **Note:** A [`Seed`][Seed] is derived from the text of the phrase, not from its entropy.
+    /// The translated phrase gives a **different seed**, and so different keys and addresses;
+    /// funds kept with one phrase can't be reached by entering the other one in a wallet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let translated = mnemonic.to_language(Language::French);
+    ///
+    /// assert_eq!(translated.entropy(), mnemonic.entropy());
+    /// assert_eq!(translated.to_language(Language::English).phrase(), phrase);
+    /// ```
+    ///
+    /// [Language]: ../language/struct.Language.html
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn to_language(&self, lang: Language) -> Mnemonic {
+        let mut translated = self.clone();
+
+        translated.lang = lang;
+
+        translated
+    }
+
+    /// Get the words of the phrase next to their translation to another [`Language`][Language]
+    ///
+    /// See [`Mnemonic::to_language()`][Mnemonic::to_language()] for why the translated phrase
+    /// gives a different seed. The result can be formatted with `Display` as a numbered table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let side_by_side = mnemonic.side_by_side(Language::French);
+    ///
+    /// assert_eq!(side_by_side.iter().next(), Some((0, "park", "munition")));
+    /// ```
+    ///
+    /// [Language]: ../language/struct.Language.html
+    /// [Mnemonic::to_language()]: ../mnemonic/struct.Mnemonic.html#method.to_language
+    pub fn side_by_side(&self, lang: Language) -> SideBySide<'_> {
+        SideBySide {
+            mnemonic: self,
+            translated: self.to_language(lang),
+        }
+    }
+}
+
+/// The words of a [`Mnemonic`][Mnemonic] next to the words of its translation, see
+/// [`Mnemonic::side_by_side()`][Mnemonic::side_by_side()]
+///
+/// Formatting it with `Display` writes one line per word, numbered from 1:
+///
+/// ```text
+///  1. park     munition
+///  2. remain   peluche
+///  3. person   nébuleux
+/// ```
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Mnemonic::side_by_side()]: ../mnemonic/struct.Mnemonic.html#method.side_by_side
+pub struct SideBySide<'a> {
+    mnemonic: &'a Mnemonic,
+    translated: Mnemonic,
+}
+
+impl<'a> SideBySide<'a> {
+    /// Iterate over the index of each word, counting from 0, the word of the original phrase and
+    /// the word of the translation
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str, &str)> {
+        self.mnemonic.words().zip(self.translated.words()).enumerate().map(|(i, (word, translation))| (i, word, translation))
+    }
+
+    /// Get the translated [`Mnemonic`][Mnemonic]
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn translated(&self) -> &Mnemonic {
+        &self.translated
+    }
+}
+
+impl<'a> fmt::Display for SideBySide<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pad by composed characters, so accents don't throw off the second column
+        let width = self.iter().map(|(_, word, _)| word.nfc().count()).max().unwrap_or(0);
+
+        for (i, word, translation) in self.iter() {
+            let padding = width - word.nfc().count();
+
+            writeln!(f, "{:>2}. {}{:padding$} {}", i + 1, word, "", translation, padding = padding)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for SideBySide<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+        self.words.zeroize();
+    }
+}
+
+// 24 of the longest words, separated by ideographic spaces
+const MAX_PHRASE_BYTES: usize = 24 * MAX_WORD_BYTES + 23 * 3;
+
+/// A phrase rendered by [`Mnemonic::phrase()`][Mnemonic::phrase()] into a fixed-size buffer
+///
+/// It dereferences to a `str`, and compares equal to strings with the same text. The buffer is
+/// sized for the longest possible phrase rather than the one it holds, so that rendering never
+/// allocates or fails; a 12 word English phrase uses around a tenth of its 933 bytes.
+///
+/// With the `zeroize` feature (enabled by default) the phrase is wiped from memory when dropped.
+///
+/// [Mnemonic::phrase()]: ./struct.Mnemonic.html#method.phrase
+#[derive(Clone)]
+pub struct Phrase {
+    bytes: [u8; MAX_PHRASE_BYTES],
+    len: usize,
+}
+
+impl Phrase {
+    fn empty() -> Phrase {
+        Phrase {
+            bytes: [0; MAX_PHRASE_BYTES],
+            len: 0,
+        }
+    }
+
+    /// Write `words` into the buffer, separated by `separator`
+    fn render<'a, I>(&mut self, words: I, separator: &str)
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        for (i, word) in words.enumerate() {
+            if i > 0 {
+                self.push(separator);
+            }
+
+            self.push(word);
+        }
+    }
+
+    fn push(&mut self, text: &str) {
+        self.bytes[self.len..self.len + text.len()].copy_from_slice(text.as_bytes());
+        self.len += text.len();
+    }
+
+    /// Get the phrase as a string slice
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.len]).expect("phrase is made of whole words")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Phrase {
+    fn drop(&mut self) {
+        self.bytes[..self.len].zeroize();
+    }
+}
+
+impl Deref for Phrase {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
 }
 
-impl AsRef<str> for Mnemonic {
+impl AsRef<str> for Phrase {
     fn as_ref(&self) -> &str {
-        self.phrase()
+        self.as_str()
+    }
+}
+
+impl PartialEq for Phrase {
+    fn eq(&self, other: &Phrase) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Phrase {}
+
+impl PartialEq<str> for Phrase {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Phrase {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Phrase {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Phrase> for str {
+    fn eq(&self, other: &Phrase) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Phrase> for &str {
+    fn eq(&self, other: &Phrase) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Phrase> for String {
+    fn eq(&self, other: &Phrase) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl fmt::Display for Phrase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for Phrase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl From<Phrase> for String {
+    fn from(val: Phrase) -> String {
+        val.as_str().to_string()
     }
 }
 
 impl fmt::Display for Mnemonic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(self.phrase(), f)
+        fmt::Display::fmt(&self.phrase(), f)
     }
 }
 
 impl fmt::Debug for Mnemonic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self.phrase(), f)
+        fmt::Debug::fmt(&self.phrase(), f)
     }
 }
 
@@ -274,7 +933,7 @@ impl fmt::LowerHex for Mnemonic {
         }
 
         for byte in self.entropy() {
-            write!(f, "{:x}", byte)?;
+            write!(f, "{:02x}", byte)?;
         }
 
         Ok(())
@@ -288,7 +947,7 @@ impl fmt::UpperHex for Mnemonic {
         }
 
         for byte in self.entropy() {
-            write!(f, "{:X}", byte)?;
+            write!(f, "{:02X}", byte)?;
         }
 
         Ok(())
@@ -304,6 +963,7 @@ impl From<Mnemonic> for String {
 #[cfg(test)]
 mod test {
     use super::*;
+    use core::mem;
 
     #[test]
     fn back_to_back() {
@@ -337,6 +997,120 @@ mod test {
         assert_eq!(entropy, mnemonic.entropy());
     }
 
+    #[test]
+    fn mnemonic_from_phrase_whitespace() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let spaced = "  crop\tcash  unable\ninsane\u{3000}eight faith inflict route frame loud box vibrant\r\n";
+
+        let mnemonic = Mnemonic::from_phrase(spaced, Language::English).unwrap();
+
+        assert_eq!(phrase, mnemonic.phrase());
+        assert!(Mnemonic::validate(spaced, Language::English).is_ok());
+        assert_eq!(MnemonicType::for_phrase(spaced).unwrap().word_count(), 12);
+    }
+
+    #[test]
+    fn mnemonic_abbreviated() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let abbreviated = "crop cash unab insa eigh fait infl rout fram loud box vibr";
+
+        let mnemonic = Mnemonic::from_abbreviated_phrase(abbreviated, Language::English).unwrap();
+
+        assert_eq!(phrase, mnemonic.phrase());
+        assert_eq!(abbreviated, mnemonic.abbreviated_phrase());
+
+        // Full words, and any unique start of a word, are accepted as well
+        assert_eq!(Mnemonic::from_abbreviated_phrase(phrase, Language::English).unwrap().phrase(), phrase);
+        assert_eq!(Mnemonic::from_abbreviated_phrase("crop cash unab insa eigh fait infl rout fram loud box vibra", Language::English).unwrap().phrase(), phrase);
+    }
+
+    #[test]
+    fn mnemonic_abbreviated_invalid() {
+        let ambiguous = Mnemonic::from_abbreviated_phrase("crop ca unab insa eigh fait infl rout fram loud box vibr", Language::English);
+        let invalid = Mnemonic::from_abbreviated_phrase("crox cash unab insa eigh fait infl rout fram loud box vibr", Language::English);
+
+        match ambiguous.unwrap_err().downcast::<ErrorKind>().unwrap() {
+            ErrorKind::AmbiguousWord(1) => {}
+            error => panic!("unexpected error: {}", error),
+        }
+
+        match invalid.unwrap_err().downcast::<ErrorKind>().unwrap() {
+            ErrorKind::InvalidWord(0) => {}
+            error => panic!("unexpected error: {}", error),
+        }
+
+        assert!(Mnemonic::from_phrase("crop cash unab insa eigh fait infl rout fram loud box vibr", Language::English).is_err());
+    }
+
+    #[test]
+    fn mnemonic_to_language() {
+        use seed::Seed;
+
+        let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English);
+
+        for &lang in Language::all() {
+            let translated = mnemonic.to_language(lang);
+
+            assert_eq!(translated.language(), lang);
+            assert_eq!(translated.entropy(), mnemonic.entropy());
+            assert_eq!(translated.to_language(Language::English).phrase(), mnemonic.phrase());
+            assert!(Mnemonic::validate(&translated.phrase(), lang).is_ok());
+
+            let side_by_side = mnemonic.side_by_side(lang);
+
+            assert_eq!(side_by_side.iter().count(), 24);
+            assert_eq!(side_by_side.to_string().lines().count(), 24);
+        }
+
+        // The seed comes from the words, not the entropy
+        let french = mnemonic.to_language(Language::French);
+
+        assert_ne!(Seed::new(&mnemonic, "").as_bytes(), Seed::new(&french, "").as_bytes());
+    }
+
+    #[test]
+    fn mnemonic_side_by_side() {
+        let mnemonic = Mnemonic::from_phrase("crop cash unable insane eight faith inflict route frame loud box vibrant", Language::English).unwrap();
+        let side_by_side = mnemonic.side_by_side(Language::French);
+        let table = side_by_side.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(side_by_side.iter().nth(3).map(|(i, word, _)| (i, word)), Some((3, "insane")));
+        assert_eq!(side_by_side.translated().language(), Language::French);
+        assert!(lines[0].starts_with(" 1. crop    "));
+        assert!(lines[11].starts_with("12. vibrant "));
+    }
+
+    #[test]
+    fn mnemonic_longest_words() {
+        for &lang in Language::all() {
+            let wordlist = lang.wordlist();
+            let longest = (0..2048).max_by_key(|&i| wordlist.get_word(Bits11::from(i)).len()).unwrap();
+
+            // All but the last word, which holds the checksum, are the longest word of the list
+            let mut bytes = [0u8; 33];
+            let mut bits = BitWriter::new(&mut bytes);
+
+            for _ in 0..24 {
+                bits.push(Bits11::from(longest));
+            }
+
+            bits.finish();
+
+            let mnemonic = Mnemonic::from_entropy(&bytes[..32], lang).unwrap();
+
+            assert_eq!(mnemonic.words().filter(|&word| word == wordlist.get_word(Bits11::from(longest))).count(), 23);
+            assert_eq!(Mnemonic::from_phrase(mnemonic.phrase(), lang).unwrap().entropy(), &bytes[..32]);
+            assert!(mnemonic.phrase().len() <= MAX_PHRASE_BYTES);
+        }
+    }
+
+    #[test]
+    fn mnemonic_size() {
+        // The phrase is rendered on demand, not kept in the `Mnemonic`
+        assert!(mem::size_of::<Mnemonic>() < 128);
+    }
+
     #[test]
     fn mnemonic_format() {
         let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English);
@@ -1,6 +1,6 @@
 use error::ErrorKind;
-use failure::Error;
-use std::fmt;
+use error::Error;
+use core::fmt;
 
 const ENTROPY_OFFSET: usize = 8;
 
@@ -29,9 +29,10 @@ const ENTROPY_OFFSET: usize = 8;
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
 /// [Seed]: ../seed/struct.Seed.html
 ///
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 pub enum MnemonicType {
     //  ... = (entropy_bits << ...)   | checksum_bits
+    #[default]
     Words12 = (128 << ENTROPY_OFFSET) | 4,
     Words15 = (160 << ENTROPY_OFFSET) | 5,
     Words18 = (192 << ENTROPY_OFFSET) | 6,
@@ -97,6 +98,9 @@ impl MnemonicType {
     /// an `Error` of kind `ErrorKind::InvalidWordLength`. The phrase will not be validated in any
     /// other way.
     ///
+    /// Words may be separated by any run of Unicode whitespace, such as the ideographic space
+    /// used in Japanese phrases.
+    ///
     /// # Example
     /// ```
     /// use bip39::{MnemonicType};
@@ -110,7 +114,7 @@ impl MnemonicType {
     ///
     /// [MnemonicType::entropy_bits()]: ./enum.MnemonicType.html#method.entropy_bits
     pub fn for_phrase(phrase: &str) -> Result<MnemonicType, Error> {
-        let word_count = phrase.split(" ").count();
+        let word_count = phrase.split_whitespace().count();
 
         Self::for_word_count(word_count)
     }
@@ -182,12 +186,6 @@ impl MnemonicType {
     }
 }
 
-impl Default for MnemonicType {
-    fn default() -> MnemonicType {
-        MnemonicType::Words12
-    }
-}
-
 impl fmt::Display for MnemonicType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} words ({}bits)", self.word_count(), self.entropy_bits())
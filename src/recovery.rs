@@ -0,0 +1,829 @@
+//! Recovering a [`Mnemonic`][Mnemonic] with unreadable or missing words
+//!
+//! The checksum of a phrase rules out most guesses for a lost word, so with one or two words
+//! unknown the remaining candidates can simply be listed. Unreadable words are marked with a
+//! [`PLACEHOLDER`][PLACEHOLDER] in the phrase; words that are missing altogether, without knowing
+//! where, are added with [`Recovery::missing_words()`][Recovery::missing_words()].
+//!
+//! Candidates are produced lazily, so a search can be stopped early. Since even a single unknown
+//! word usually leaves over a hundred valid phrases, a [filter][Recovery::matching()] that
+//! compares each candidate with something known about the wallet, like an address or the
+//! fingerprint of its master key, is what narrows them down to one.
+//!
+//! ```rust
+//! use bip39::Language;
+//! use bip39::recovery::Recovery;
+//!
+//! // the eighth word is unreadable
+//! let phrase = "park remain person kitchen mule spell knee ? position rail grid ankle";
+//! let candidates = Recovery::new(phrase, Language::English).unwrap().candidates().unwrap();
+//!
+//! for mnemonic in candidates {
+//!     println!("{}", mnemonic.phrase());
+//! }
+//! ```
+//!
+//! Phrases that were written down with a typo in one word, or with two neighbouring words
+//! swapped, fail the checksum instead. [`corrections()`][corrections()] lists the ways to fix
+//! those, and [`invalid_words()`][invalid_words()] points out the words that aren't in the word
+//! list at all, with suggestions for each.
+//!
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [corrections()]: ./fn.corrections.html
+//! [invalid_words()]: ./fn.invalid_words.html
+//! [PLACEHOLDER]: ./constant.PLACEHOLDER.html
+//! [Recovery::missing_words()]: ./struct.Recovery.html#method.missing_words
+//! [Recovery::matching()]: ./struct.Recovery.html#method.matching
+//!
+
+#[cfg(feature = "bip32")]
+use bip32::{ExtendedPrivKey, Network};
+use crypto::sha256_first_byte;
+use error::ErrorKind;
+use error::Error;
+use language::Language;
+use mnemonic::Mnemonic;
+use mnemonic_type::MnemonicType;
+#[cfg(feature = "bip32")]
+use seed::Seed;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+use unicode_normalization::char::is_combining_mark;
+use util::{edit_distance, normalize_utf8, typo_distance, BitWriter, Bits11};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The word that marks an unreadable word in a phrase
+pub const PLACEHOLDER: &str = "?";
+
+/// The most words that can be unknown, placeholders and missing words together
+pub const MAX_UNKNOWN_WORDS: usize = 4;
+
+// Candidates tried between calls of the progress callback
+const PROGRESS_INTERVAL: u64 = 1 << 16;
+
+/// The most suggestions [`invalid_words()`][invalid_words()] gives for each word
+///
+/// [invalid_words()]: ./fn.invalid_words.html
+pub const MAX_SUGGESTIONS: usize = 5;
+
+// The most edits a word can be away from a suggestion for it
+const MAX_SUGGESTION_EDITS: usize = 2;
+
+type Filter = Box<dyn FnMut(&Mnemonic) -> bool + Send>;
+type ProgressCallback = Box<dyn FnMut(Progress) + Send>;
+
+/// How far a search through the candidates has come
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    tried: u64,
+    total: u64,
+}
+
+impl Progress {
+    /// Get the number of candidates tried so far
+    pub fn tried(&self) -> u64 {
+        self.tried
+    }
+
+    /// Get the number of candidates that will be tried in total
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// The known part of a phrase, and how to search for the rest
+///
+/// This is a builder, call [`Recovery::candidates()`][Recovery::candidates()] to start the
+/// search.
+///
+/// With the `zeroize` feature (enabled by default) the known words are wiped from memory when
+/// dropped.
+///
+/// [Recovery::candidates()]: ./struct.Recovery.html#method.candidates
+pub struct Recovery {
+    // the words of the phrase, `None` for placeholders
+    words: Vec<Option<u16>>,
+    missing: usize,
+    lang: Language,
+    cancel: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+    filter: Option<Filter>,
+}
+
+impl Recovery {
+    /// Start a recovery from a phrase that uses [`PLACEHOLDER`][PLACEHOLDER] for unreadable words
+    ///
+    /// The phrase is normalized like in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    /// Returns an `Error` of kind `ErrorKind::InvalidWord` if a word isn't in the word list of
+    /// `lang`.
+    ///
+    /// [PLACEHOLDER]: ./constant.PLACEHOLDER.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn new(phrase: &str, lang: Language) -> Result<Recovery, Error> {
+        let wordlist = lang.wordlist();
+        let mut words = Vec::with_capacity(24);
+
+        for (index, word) in normalize_utf8(phrase).split_whitespace().enumerate() {
+            if word == PLACEHOLDER {
+                words.push(None);
+            } else {
+                match wordlist.get_bits(word) {
+                    Some(bits) => words.push(Some(u16::from(bits))),
+                    None => Err(ErrorKind::InvalidWord(index))?,
+                }
+            }
+        }
+
+        Ok(Recovery {
+            words,
+            missing: 0,
+            lang,
+            cancel: None,
+            progress: None,
+            filter: None,
+        })
+    }
+
+    /// Set the number of words that are missing from the phrase at unknown positions
+    ///
+    /// Every position is tried for them, and a phrase that can be completed in more than one way
+    /// is still only produced once.
+    pub fn missing_words(mut self, missing: usize) -> Recovery {
+        self.missing = missing;
+        self
+    }
+
+    /// Stop the search when `cancel` is set, which can be done from another thread
+    pub fn cancel_flag(mut self, cancel: Arc<AtomicBool>) -> Recovery {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Call `progress` periodically during the search, and once when it is done
+    ///
+    /// Valid candidates can be far apart, so this is the way to show progress while waiting for
+    /// the next one.
+    pub fn on_progress<F>(mut self, progress: F) -> Recovery
+    where
+        F: FnMut(Progress) + Send + 'static,
+    {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Only produce the candidates for which `filter` returns `true`
+    ///
+    /// This is where a candidate is compared with a known address of the wallet, or anything else
+    /// the caller knows about it. Only phrases with a valid checksum are passed to the filter.
+    pub fn matching<F>(mut self, filter: F) -> Recovery
+    where
+        F: FnMut(&Mnemonic) -> bool + Send + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Only produce the candidates whose BIP0032 master key has `fingerprint` with `passphrase`
+    ///
+    /// The fingerprint is shown by most hardware wallets, and stored in PSBTs and output
+    /// descriptors. Replaces any filter set with [`Recovery::matching()`][Recovery::matching()].
+    /// Only available with the `bip32` feature.
+    ///
+    /// [Recovery::matching()]: ./struct.Recovery.html#method.matching
+    #[cfg(feature = "bip32")]
+    pub fn matching_fingerprint(self, fingerprint: [u8; 4], passphrase: &str) -> Recovery {
+        let passphrase = Passphrase(passphrase.to_string());
+
+        self.matching(move |mnemonic| {
+            let seed = Seed::new(mnemonic, &passphrase.0);
+
+            match ExtendedPrivKey::new_master(&seed, Network::Mainnet) {
+                Ok(master) => master.fingerprint() == fingerprint,
+                Err(_) => false,
+            }
+        })
+    }
+
+    /// Start the search, returning an iterator over the candidates
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidWordLength` if the words of the phrase and
+    /// the missing words don't add up to a valid length, or `ErrorKind::TooManyUnknownWords` if
+    /// more than [`MAX_UNKNOWN_WORDS`][MAX_UNKNOWN_WORDS] are unknown.
+    ///
+    /// [MAX_UNKNOWN_WORDS]: ./constant.MAX_UNKNOWN_WORDS.html
+    pub fn candidates(self) -> Result<Candidates, Error> {
+        let mtype = MnemonicType::for_word_count(self.words.len() + self.missing)?;
+        let unknown = self.words.iter().filter(|word| word.is_none()).count() + self.missing;
+
+        if unknown > MAX_UNKNOWN_WORDS {
+            Err(ErrorKind::TooManyUnknownWords(unknown))?;
+        }
+
+        let mut candidates = Candidates {
+            mtype,
+            positions: (0..self.missing).collect(),
+            unknown: Vec::with_capacity(unknown),
+            phrase: vec![0; mtype.word_count()],
+            buffer: [0; 33],
+            counter: 0,
+            space: 0,
+            tried: 0,
+            total: 0,
+            done: false,
+            recovery: self,
+        };
+
+        // Count the candidates for every choice of missing positions up front, for progress
+        loop {
+            candidates.fill_template();
+            candidates.total += candidates.current_space();
+
+            if !candidates.next_positions() {
+                break;
+            }
+        }
+
+        for (i, position) in candidates.positions.iter_mut().enumerate() {
+            *position = i;
+        }
+
+        candidates.fill_template();
+        candidates.space = candidates.current_space();
+
+        Ok(candidates)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Recovery {
+    fn drop(&mut self) {
+        self.words.zeroize();
+    }
+}
+
+impl fmt::Debug for Recovery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Recovery").field("missing", &self.missing).finish()
+    }
+}
+
+/// An iterator over the phrases that complete a [`Recovery`][Recovery] and pass the checksum
+///
+/// Candidates are checked one at a time as the iterator is advanced, and the iterator ends early
+/// if the search is cancelled.
+///
+/// [Recovery]: ./struct.Recovery.html
+pub struct Candidates {
+    recovery: Recovery,
+    mtype: MnemonicType,
+    // the positions of the missing words in the current attempt
+    positions: Vec<usize>,
+    // the positions in `phrase` to fill in, in order
+    unknown: Vec<usize>,
+    phrase: Vec<u16>,
+    buffer: [u8; 33],
+    counter: u64,
+    space: u64,
+    tried: u64,
+    total: u64,
+    done: bool,
+}
+
+impl Candidates {
+    /// Get the [`Progress`][Progress] of the search
+    ///
+    /// [Progress]: ./struct.Progress.html
+    pub fn progress(&self) -> Progress {
+        Progress {
+            tried: self.tried,
+            total: self.total,
+        }
+    }
+
+    fn report_progress(&mut self) {
+        let progress = self.progress();
+
+        if let Some(ref mut callback) = self.recovery.progress {
+            callback(progress);
+        }
+    }
+
+    fn cancelled(&self) -> bool {
+        match self.recovery.cancel {
+            Some(ref cancel) => cancel.load(Ordering::Relaxed),
+            None => false,
+        }
+    }
+
+    /// Put the known words around the current missing positions, and collect the unknown ones
+    fn fill_template(&mut self) {
+        let mut words = self.recovery.words.iter();
+        let mut positions = self.positions.iter().peekable();
+
+        self.unknown.clear();
+
+        for (i, slot) in self.phrase.iter_mut().enumerate() {
+            let word = if positions.peek() == Some(&&i) {
+                positions.next();
+                None
+            } else {
+                *words.next().expect("words and missing words add up to the length")
+            };
+
+            match word {
+                Some(word) => *slot = word,
+                None => self.unknown.push(i),
+            }
+        }
+    }
+
+    /// Get the number of candidates for the current missing positions
+    ///
+    /// If the last word is unknown it isn't tried, but worked out from the checksum.
+    fn current_space(&self) -> u64 {
+        let count = self.unknown.len() as u32;
+
+        match self.unknown.last() {
+            Some(&last) if last == self.phrase.len() - 1 => 2048u64.pow(count - 1) << (11 - self.mtype.checksum_bits()),
+            _ => 2048u64.pow(count),
+        }
+    }
+
+    /// Move on to the next choice of missing positions, returning `false` when all are done
+    fn next_positions(&mut self) -> bool {
+        let word_count = self.phrase.len();
+        let missing = self.positions.len();
+
+        for i in (0..missing).rev() {
+            if self.positions[i] < word_count - missing + i {
+                self.positions[i] += 1;
+
+                for j in i + 1..missing {
+                    self.positions[j] = self.positions[j - 1] + 1;
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Fill in the unknown words for `counter`, and check the checksum
+    fn try_candidate(&mut self, mut counter: u64) -> bool {
+        let word_count = self.phrase.len();
+        let checksum_bits = self.mtype.checksum_bits() as u32;
+        let derived = self.unknown.last() == Some(&(word_count - 1));
+
+        for (i, &position) in self.unknown.iter().enumerate() {
+            if derived && i == self.unknown.len() - 1 {
+                self.phrase[position] = (counter as u16) << checksum_bits;
+            } else {
+                self.phrase[position] = (counter % 2048) as u16;
+                counter /= 2048;
+            }
+        }
+
+        pack_words(&self.phrase, &mut self.buffer);
+
+        if derived {
+            let entropy_bytes = self.mtype.entropy_bits() / 8;
+
+            self.phrase[word_count - 1] |= (sha256_first_byte(&self.buffer[..entropy_bytes]) >> (8 - checksum_bits)) as u16;
+
+            return true;
+        }
+
+        checksum_matches(&self.phrase, self.mtype, &self.buffer)
+    }
+
+    /// Whether the current missing positions are the leftmost ones the known words fit around,
+    /// so that a phrase that can be completed in several ways is only produced once
+    fn is_canonical(&self) -> bool {
+        if self.positions.is_empty() {
+            return true;
+        }
+
+        let mut words = self.recovery.words.iter().peekable();
+        let mut positions = self.positions.iter();
+
+        for (i, &word) in self.phrase.iter().enumerate() {
+            match words.peek() {
+                Some(&&known) if known.is_none() || known == Some(word) => {
+                    words.next();
+                }
+                _ => {
+                    if positions.next() != Some(&i) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for Candidates {
+    type Item = Mnemonic;
+
+    fn next(&mut self) -> Option<Mnemonic> {
+        while !self.done {
+            if self.cancelled() {
+                self.done = true;
+
+                return None;
+            }
+
+            if self.counter == self.space {
+                if !self.next_positions() {
+                    self.done = true;
+                    self.report_progress();
+
+                    return None;
+                }
+
+                self.fill_template();
+                self.counter = 0;
+                self.space = self.current_space();
+
+                continue;
+            }
+
+            let counter = self.counter;
+
+            self.counter += 1;
+            self.tried += 1;
+
+            if self.tried % PROGRESS_INTERVAL == 0 {
+                self.report_progress();
+            }
+
+            if !self.try_candidate(counter) || !self.is_canonical() {
+                continue;
+            }
+
+            let entropy_bytes = self.mtype.entropy_bits() / 8;
+            let mnemonic = Mnemonic::from_entropy(&self.buffer[..entropy_bytes], self.recovery.lang)
+                .expect("entropy has the length of a mnemonic type");
+
+            let matches = match self.recovery.filter {
+                Some(ref mut filter) => filter(&mnemonic),
+                None => true,
+            };
+
+            if matches {
+                return Some(mnemonic);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Candidates {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl fmt::Debug for Candidates {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Candidates").field("progress", &self.progress()).finish()
+    }
+}
+
+/// A change to a phrase that makes its checksum valid, see [`corrections()`][corrections()]
+///
+/// [corrections()]: ./fn.corrections.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrectionKind {
+    /// The word at `position` was replaced with a word `distance` edits away from it
+    Substitution {
+        /// The position of the word in the phrase, counting from 0
+        position: usize,
+        /// The number of characters inserted, removed, replaced or swapped
+        distance: usize,
+    },
+    /// The words at `position` and `position + 1` were swapped
+    Swap {
+        /// The position of the first word in the phrase, counting from 0
+        position: usize,
+    },
+}
+
+impl CorrectionKind {
+    // Lower is more plausible. A swapped pair is about as likely as a single typo, but less
+    // likely than a typo when it comes to a tie.
+    fn rank(&self) -> (usize, usize, usize) {
+        match *self {
+            CorrectionKind::Substitution { position, distance } => (distance, 0, position),
+            CorrectionKind::Swap { position } => (1, 1, position),
+        }
+    }
+}
+
+/// A corrected phrase, with the change that was made
+#[derive(Debug, Clone)]
+pub struct Correction {
+    mnemonic: Mnemonic,
+    kind: CorrectionKind,
+}
+
+impl Correction {
+    /// Get the corrected [`Mnemonic`][Mnemonic]
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.mnemonic
+    }
+
+    /// Consume the `Correction` and return the [`Mnemonic`][Mnemonic]
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn into_mnemonic(self) -> Mnemonic {
+        self.mnemonic
+    }
+
+    /// Get the [`CorrectionKind`][CorrectionKind] that was made
+    ///
+    /// [CorrectionKind]: ./enum.CorrectionKind.html
+    pub fn kind(&self) -> CorrectionKind {
+        self.kind
+    }
+}
+
+/// Find the phrases one mistake away from `phrase` that have a valid checksum, most plausible first
+///
+/// Two kinds of mistakes are tried: replacing one word with a word at most `max_distance` edits
+/// away from it, and swapping two neighbouring words. A word that isn't in the word list of
+/// `lang` can only be fixed by replacing it, so if there is more than one no corrections are
+/// found. Corrections are ranked by the number of edits, with ties going to earlier words.
+///
+/// The phrase is normalized like in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+/// Returns an `Error` of kind `ErrorKind::InvalidWordLength` if it doesn't have a valid number
+/// of words.
+///
+/// # Example
+///
+/// ```
+/// use bip39::Language;
+/// use bip39::recovery::corrections;
+///
+/// // "kitchen" was written as "kitten"
+/// let phrase = "park remain person kitten mule spell knee armed position rail grid ankle";
+/// let corrections = corrections(phrase, Language::English, 2).unwrap();
+///
+/// assert!(corrections.iter().any(|correction| correction.mnemonic().phrase().contains("kitchen")));
+/// ```
+///
+/// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+pub fn corrections(phrase: &str, lang: Language, max_distance: usize) -> Result<Vec<Correction>, Error> {
+    let phrase = normalize_utf8(phrase);
+    let typed: Vec<&str> = phrase.split_whitespace().collect();
+    let mtype = MnemonicType::for_word_count(typed.len())?;
+    let wordlist = lang.wordlist();
+
+    #[allow(unused_mut)]
+    let mut words: Vec<Option<u16>> = typed.iter().map(|word| wordlist.index_of(word)).collect();
+    let unknown = words.iter().filter(|word| word.is_none()).count();
+
+    let mut candidate = vec![0u16; typed.len()];
+    let mut corrections = Vec::new();
+
+    let mut add = |candidate: &[u16], kind: CorrectionKind| {
+        let mut buffer = [0u8; 33];
+
+        pack_words(candidate, &mut buffer);
+
+        if checksum_matches(candidate, mtype, &buffer) {
+            let entropy = &buffer[..mtype.entropy_bits() / 8];
+            let mnemonic = Mnemonic::from_entropy(entropy, lang).expect("entropy has the length of a mnemonic type");
+
+            corrections.push(Correction {
+                mnemonic,
+                kind,
+            });
+        }
+
+        #[cfg(feature = "zeroize")]
+        buffer.zeroize();
+    };
+
+    for position in 0..typed.len() {
+        // Only an unknown word can be the one that was mistyped, if there is one
+        if unknown > 1 || (unknown == 1 && words[position].is_some()) {
+            continue;
+        }
+
+        for (i, &word) in words.iter().enumerate() {
+            candidate[i] = word.unwrap_or(0);
+        }
+
+        for (index, replacement) in wordlist.iter().enumerate() {
+            if words[position] == Some(index as u16) {
+                continue;
+            }
+
+            let distance = edit_distance(typed[position], replacement);
+
+            if distance <= max_distance {
+                candidate[position] = index as u16;
+                add(&candidate, CorrectionKind::Substitution { position, distance });
+            }
+        }
+    }
+
+    if unknown == 0 {
+        for position in 0..typed.len() - 1 {
+            if words[position] == words[position + 1] {
+                continue;
+            }
+
+            for (i, &word) in words.iter().enumerate() {
+                candidate[i] = word.expect("all words are known");
+            }
+
+            candidate.swap(position, position + 1);
+            add(&candidate, CorrectionKind::Swap { position });
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    {
+        words.zeroize();
+        candidate.zeroize();
+    }
+
+    corrections.sort_by_key(|correction| correction.kind.rank());
+
+    Ok(corrections)
+}
+
+/// A word of a phrase that isn't in the word list, see [`invalid_words()`][invalid_words()]
+///
+/// [invalid_words()]: ./fn.invalid_words.html
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidWord {
+    index: usize,
+    word: String,
+    suggestions: Vec<&'static str>,
+}
+
+impl InvalidWord {
+    /// Get the position of the word in the phrase, counting from 0
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the word as it appears in the normalized phrase
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Get the closest words from the word list, closest first
+    pub fn suggestions(&self) -> &[&'static str] {
+        &self.suggestions
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for InvalidWord {
+    fn drop(&mut self) {
+        self.word.zeroize();
+    }
+}
+
+impl fmt::Debug for InvalidWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InvalidWord").field("index", &self.index).finish()
+    }
+}
+
+/// Find every word of `phrase` that isn't in the word list of `lang`, each with up to
+/// [`MAX_SUGGESTIONS`][MAX_SUGGESTIONS] [suggestions][suggestions()] to replace it
+///
+/// Where [`Mnemonic::validate()`][Mnemonic::validate()] stops at the first invalid word, this
+/// reports all of them, so that each can be corrected at once. The phrase is normalized like in
+/// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], and isn't checked for its length or
+/// checksum.
+///
+/// # Example
+///
+/// ```
+/// use bip39::Language;
+/// use bip39::recovery::invalid_words;
+///
+/// let invalid = invalid_words("park remain persn kitchen mule spell knee armed position rail gird ankle", Language::English);
+///
+/// assert_eq!(invalid.len(), 2);
+/// assert_eq!(invalid[0].index(), 2);
+/// assert_eq!(invalid[0].suggestions()[0], "person");
+/// assert_eq!(invalid[1].index(), 10);
+/// ```
+///
+/// [MAX_SUGGESTIONS]: ./constant.MAX_SUGGESTIONS.html
+/// [suggestions()]: ./fn.suggestions.html
+/// [Mnemonic::validate()]: ../mnemonic/struct.Mnemonic.html#method.validate
+/// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+pub fn invalid_words(phrase: &str, lang: Language) -> Vec<InvalidWord> {
+    let wordlist = lang.wordlist();
+
+    normalize_utf8(phrase)
+        .split_whitespace()
+        .enumerate()
+        .filter(|&(_, word)| wordlist.index_of(word).is_none())
+        .map(|(index, word)| InvalidWord {
+            index,
+            word: word.to_string(),
+            suggestions: suggestions(word, lang, MAX_SUGGESTIONS),
+        })
+        .collect()
+}
+
+/// Find up to `limit` words in the word list of `lang` that `word` could be a mistyped version
+/// of, closest first
+///
+/// Words at most two edits away are suggested. Accents and case are ignored, and replacing a
+/// letter with one from a neighbouring key on a QWERTY keyboard only counts as half an edit.
+/// Ties go to the word with the fewest edits when accents are taken into account, then to the
+/// word that comes first in the list.
+///
+/// # Example
+///
+/// ```
+/// use bip39::Language;
+/// use bip39::recovery::suggestions;
+///
+/// // f is next to g on the keyboard
+/// assert_eq!(suggestions("frid", Language::English, 3)[0], "grid");
+/// ```
+pub fn suggestions(word: &str, lang: Language, limit: usize) -> Vec<&'static str> {
+    let word = normalize_utf8(word);
+    #[allow(unused_mut)]
+    let mut folded = fold_accents(&word);
+
+    let mut ranked: Vec<(usize, usize, &'static str)> = lang
+        .wordlist()
+        .iter()
+        .filter_map(|candidate| {
+            let distance = typo_distance(&folded, &fold_accents(candidate));
+
+            if distance <= 2 * MAX_SUGGESTION_EDITS {
+                Some((distance, edit_distance(&word, candidate), candidate))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    #[cfg(feature = "zeroize")]
+    folded.zeroize();
+
+    // stable, so ties keep their order in the list
+    ranked.sort_by_key(|&(distance, edits, _)| (distance, edits));
+
+    ranked.into_iter().take(limit).map(|(_, _, candidate)| candidate).collect()
+}
+
+/// Lowercase an NFKD normalized word and drop its accents
+fn fold_accents(word: &str) -> String {
+    word.chars().filter(|&c| !is_combining_mark(c)).flat_map(char::to_lowercase).collect()
+}
+
+/// Whether the checksum bits in the last of `words` match the entropy packed in `buffer`
+fn checksum_matches(words: &[u16], mtype: MnemonicType, buffer: &[u8; 33]) -> bool {
+    let checksum_bits = mtype.checksum_bits() as u32;
+    let checksum = (sha256_first_byte(&buffer[..mtype.entropy_bits() / 8]) >> (8 - checksum_bits)) as u16;
+
+    words[words.len() - 1] & ((1 << checksum_bits) - 1) == checksum
+}
+
+/// A passphrase captured by a filter, wiped from memory when the filter is dropped
+#[cfg(feature = "bip32")]
+struct Passphrase(String);
+
+#[cfg(all(feature = "bip32", feature = "zeroize"))]
+impl Drop for Passphrase {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Write 11-bit words to `buffer`, most significant bit first, like a parsed phrase
+fn pack_words(words: &[u16], buffer: &mut [u8; 33]) {
+    let mut bits = BitWriter::new(buffer);
+
+    for &word in words {
+        bits.push(Bits11::from(word));
+    }
+
+    bits.finish();
+}
@@ -1,6 +1,11 @@
 use crypto::pbkdf2;
 use mnemonic::Mnemonic;
-use std::fmt;
+use core::fmt;
+use unicode_normalization::UnicodeNormalization;
+#[cfg(feature = "electrum")]
+use electrum::{normalize_text, ElectrumMnemonic};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 /// The secret value used to derive HD wallet addresses from a [`Mnemonic`][Mnemonic] phrase.
 ///
@@ -9,25 +14,97 @@ use std::fmt;
 /// a valid, intact mnemonic phrase can be used to derive HD wallet addresses.
 ///
 /// To get the raw byte value use [`Seed::as_bytes()`][Seed::as_bytes()]. These can be used to derive
-/// HD wallet addresses using another crate (deriving HD wallet addresses is outside the scope of this
-/// crate and the BIP39 standard).
+/// HD wallet addresses using another crate, or with the [`bip32`][bip32] module of this crate when
+/// the `bip32` feature is enabled.
+///
+/// With the `zeroize` feature (enabled by default) the seed is wiped from memory when dropped.
 ///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
 /// [Seed]: ./seed/struct.Seed.html
 /// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
-
+/// [bip32]: ./bip32/index.html
 #[derive(Clone)]
 pub struct Seed {
-    bytes: Vec<u8>,
+    bytes: [u8; 64],
 }
 
 impl Seed {
     /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password.
     ///
+    /// As specified in BIP0039, the seed is derived by running PBKDF2-HMAC-SHA512 over the
+    /// mnemonic phrase, salted with `"mnemonic"` followed by the password. Both are normalized to
+    /// Unicode NFKD first, which also turns the ideographic spaces of a Japanese phrase into
+    /// regular spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "TREZOR");
+    ///
+    /// assert_eq!(format!("{:x}", seed), "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+    /// ```
+    ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
     pub fn new(mnemonic: &Mnemonic, password: &str) -> Self {
-        let salt = format!("mnemonic{}", password);
-        let bytes = pbkdf2(mnemonic.entropy(), &salt);
+        let phrase = mnemonic.normalized_phrase();
+        let bytes = pbkdf2(phrase.as_str().as_bytes(), "mnemonic".chars().chain(password.nfkd()));
+
+        Self {
+            bytes,
+        }
+    }
+
+    /// Generates a seed the way `0.6.0-beta.1` of this crate did, using the entropy of the
+    /// [`Mnemonic`][Mnemonic] as the PBKDF2 input instead of the phrase.
+    ///
+    /// **This is not compatible with BIP0039** or any other wallet software, it only exists so
+    /// that keys derived with that release can still be recovered and moved elsewhere. Use
+    /// [`Seed::new()`][Seed::new()] for everything else.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/struct.Seed.html#method.new
+    pub fn new_legacy(mnemonic: &Mnemonic, password: &str) -> Self {
+        let bytes = pbkdf2(mnemonic.entropy(), "mnemonic".chars().chain(password.chars()));
+
+        Self {
+            bytes,
+        }
+    }
+
+    /// Generates the seed of an Electrum wallet from an [`ElectrumMnemonic`][ElectrumMnemonic]
+    /// and the passphrase.
+    ///
+    /// This is PBKDF2-HMAC-SHA512 over the normalized phrase like [`Seed::new()`][Seed::new()],
+    /// but salted with `"electrum"` followed by the passphrase, which is normalized the same way
+    /// as the phrase (lower case and without accents).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Seed;
+    /// use bip39::electrum::ElectrumMnemonic;
+    ///
+    /// let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+    /// let mnemonic = ElectrumMnemonic::from_phrase(phrase).unwrap();
+    /// let seed = Seed::new_electrum(&mnemonic, "");
+    ///
+    /// assert_eq!(format!("{:x}", seed), "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756");
+    /// ```
+    ///
+    /// [ElectrumMnemonic]: ./electrum/struct.ElectrumMnemonic.html
+    /// [Seed::new()]: ./seed/struct.Seed.html#method.new
+    #[cfg(feature = "electrum")]
+    pub fn new_electrum(mnemonic: &ElectrumMnemonic, passphrase: &str) -> Self {
+        #[allow(unused_mut)]
+        let mut passphrase = normalize_text(passphrase);
+        let bytes = pbkdf2(mnemonic.phrase().as_bytes(), "electrum".chars().chain(passphrase.chars()));
+
+        #[cfg(feature = "zeroize")]
+        passphrase.zeroize();
 
         Self {
             bytes,
@@ -40,6 +117,13 @@ impl Seed {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
 impl AsRef<[u8]> for Seed {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -59,7 +143,7 @@ impl fmt::LowerHex for Seed {
         }
 
         for byte in &self.bytes {
-            write!(f, "{:x}", byte)?;
+            write!(f, "{:02x}", byte)?;
         }
 
         Ok(())
@@ -73,7 +157,7 @@ impl fmt::UpperHex for Seed {
         }
 
         for byte in &self.bytes {
-            write!(f, "{:X}", byte)?;
+            write!(f, "{:02X}", byte)?;
         }
 
         Ok(())
@@ -0,0 +1,321 @@
+//! [SLIP-0010][slip10-standard] key derivation for ed25519 and NIST P-256 from a [`Seed`][Seed]
+//!
+//! SLIP-0010 generalizes [BIP0032][bip32] to other curves, with a curve specific HMAC key for the
+//! master key. Paths are the same [`DerivationPath`][DerivationPath]s used by the `bip32` module,
+//! which should be used for secp256k1 keys. Ed25519 only supports hardened derivation.
+//!
+//! Only available with the `slip10` feature, which is off by default.
+//!
+//! ```rust
+//! use bip39::{Mnemonic, Language, Seed};
+//! use bip39::bip32::DerivationPath;
+//! use bip39::slip10::{Curve, ExtendedPrivKey};
+//!
+//! let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! let seed = Seed::new(&mnemonic, "");
+//!
+//! // SEP-0005 account 0 for Stellar
+//! let path: DerivationPath = "m/44'/148'/0'".parse().unwrap();
+//! let key = ExtendedPrivKey::new_master(&seed, Curve::Ed25519).unwrap().derive_path(&path).unwrap();
+//!
+//! let private_key: &[u8; 32] = key.private_key();
+//! let public_key: [u8; 32] = key.ed25519_public_key().unwrap();
+//! ```
+//!
+//! [slip10-standard]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+//! [bip32]: ../bip32/index.html
+//! [DerivationPath]: ../bip32/struct.DerivationPath.html
+//! [Seed]: ../seed/struct.Seed.html
+//!
+
+use bip32::{ChildNumber, DerivationPath};
+use crypto::{hash160, hmac_sha512};
+use ed25519_dalek::SigningKey;
+use error::ErrorKind;
+use error::Error;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::elliptic_curve::{Field, PrimeField};
+use p256::{FieldBytes, ProjectivePoint, Scalar};
+use seed::Seed;
+use std::fmt;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The elliptic curve keys are derived for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Curve {
+    /// Ed25519, as used by Solana, Stellar and Tezos among others. Only supports hardened derivation.
+    Ed25519,
+    /// NIST P-256, also known as secp256r1 or prime256v1
+    NistP256,
+}
+
+impl Curve {
+    fn master_key(&self) -> &'static [u8] {
+        match *self {
+            Curve::Ed25519 => b"ed25519 seed",
+            Curve::NistP256 => b"Nist256p1 seed",
+        }
+    }
+}
+
+/// An extended private key on one of the SLIP-0010 [`Curve`][Curve]s
+///
+/// Create the master key of a wallet with [`ExtendedPrivKey::new_master()`][ExtendedPrivKey::new_master()],
+/// then derive keys from it with [`ExtendedPrivKey::derive_path()`][ExtendedPrivKey::derive_path()].
+///
+/// With the `zeroize` feature (enabled by default) the private key and chain code are wiped from
+/// memory when dropped.
+///
+/// [Curve]: ./enum.Curve.html
+/// [ExtendedPrivKey::new_master()]: ./struct.ExtendedPrivKey.html#method.new_master
+/// [ExtendedPrivKey::derive_path()]: ./struct.ExtendedPrivKey.html#method.derive_path
+#[derive(Clone)]
+pub struct ExtendedPrivKey {
+    curve: Curve,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: [u8; 32],
+    private_key: [u8; 32],
+}
+
+impl ExtendedPrivKey {
+    /// Create the master key of a wallet on `curve` from a [`Seed`][Seed]
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    pub fn new_master(seed: &Seed, curve: Curve) -> Result<ExtendedPrivKey, Error> {
+        Self::from_seed_bytes(seed.as_bytes(), curve)
+    }
+
+    /// Create the master key of a wallet on `curve` from raw seed bytes
+    ///
+    /// This is meant for seeds that did not come from a BIP0039 [`Mnemonic`][Mnemonic], use
+    /// [`ExtendedPrivKey::new_master()`][ExtendedPrivKey::new_master()] otherwise. Seeds must be
+    /// between 16 and 64 bytes long, any other length returns an `Error` of kind
+    /// `ErrorKind::InvalidSeedLength`.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [ExtendedPrivKey::new_master()]: ./struct.ExtendedPrivKey.html#method.new_master
+    pub fn from_seed_bytes(seed: &[u8], curve: Curve) -> Result<ExtendedPrivKey, Error> {
+        if seed.len() < 16 || seed.len() > 64 {
+            Err(ErrorKind::InvalidSeedLength(seed.len()))?;
+        }
+
+        let mut output = hmac_sha512(curve.master_key(), &[seed]);
+
+        // For NIST P-256 the left half might not be a valid key, in which case the whole output is
+        // hashed again until it is.
+        while curve == Curve::NistP256 && p256_scalar(&output[..32]).map_or(true, |key| bool::from(key.is_zero())) {
+            output = hmac_sha512(curve.master_key(), &[&output]);
+        }
+
+        let key = Self::from_hmac_output(&output, curve, 0, [0; 4], ChildNumber::Normal(0), &output[..32]);
+
+        #[cfg(feature = "zeroize")]
+        output.zeroize();
+
+        Ok(key)
+    }
+
+    fn from_hmac_output(
+        output: &[u8; 64],
+        curve: Curve,
+        depth: u8,
+        parent_fingerprint: [u8; 4],
+        child_number: ChildNumber,
+        key: &[u8],
+    ) -> ExtendedPrivKey {
+        let mut chain_code = [0u8; 32];
+        let mut private_key = [0u8; 32];
+
+        chain_code.copy_from_slice(&output[32..]);
+        private_key.copy_from_slice(key);
+
+        ExtendedPrivKey {
+            curve,
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            private_key,
+        }
+    }
+
+    /// Derive the child key at `child`
+    ///
+    /// Ed25519 keys only have hardened children, asking for a normal child returns an `Error` of
+    /// kind `ErrorKind::HardenedDerivationRequired`.
+    pub fn derive_child(&self, child: ChildNumber) -> Result<ExtendedPrivKey, Error> {
+        if self.curve == Curve::Ed25519 && !child.is_hardened() {
+            Err(ErrorKind::HardenedDerivationRequired)?;
+        }
+
+        let raw = child.to_raw()?.to_be_bytes();
+        let depth = self.depth.checked_add(1).ok_or(ErrorKind::InvalidDerivedKey)?;
+
+        let mut output = if child.is_hardened() {
+            hmac_sha512(&self.chain_code, &[&[0], &self.private_key, &raw])
+        } else {
+            hmac_sha512(&self.chain_code, &[&self.public_key(), &raw])
+        };
+
+        let key = match self.curve {
+            Curve::Ed25519 => {
+                Self::from_hmac_output(&output, self.curve, depth, self.fingerprint(), child, &output[..32])
+            }
+            Curve::NistP256 => {
+                let parent = p256_scalar(&self.private_key).expect("private key is always valid");
+
+                // If the result isn't a valid key, the derivation is repeated from the right half
+                // of the output until it is.
+                #[allow(unused_mut)]
+                let mut key: [u8; 32] = loop {
+                    if let Some(tweak) = p256_scalar(&output[..32]) {
+                        let key = tweak + parent;
+
+                        if !bool::from(key.is_zero()) {
+                            break key.to_bytes().into();
+                        }
+                    }
+
+                    let mut chain_code = [0u8; 32];
+
+                    chain_code.copy_from_slice(&output[32..]);
+                    output = hmac_sha512(&self.chain_code, &[&[1], &chain_code, &raw]);
+                };
+
+                let derived = Self::from_hmac_output(&output, self.curve, depth, self.fingerprint(), child, &key);
+
+                #[cfg(feature = "zeroize")]
+                key.zeroize();
+
+                derived
+            }
+        };
+
+        #[cfg(feature = "zeroize")]
+        output.zeroize();
+
+        Ok(key)
+    }
+
+    /// Derive the descendant at `path`, relative to this key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<ExtendedPrivKey, Error> {
+        let mut key = self.clone();
+
+        for child in path {
+            key = key.derive_child(*child)?;
+        }
+
+        Ok(key)
+    }
+
+    /// Get the raw 32 byte private key
+    ///
+    /// For ed25519 this is the secret key seed as defined by RFC 8032, not an expanded key.
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.private_key
+    }
+
+    /// Get the 33 byte public key as defined by SLIP-0010
+    ///
+    /// This is the compressed point for NIST P-256. For ed25519 it is the 32 byte public key
+    /// prefixed with a zero byte, see [`ExtendedPrivKey::ed25519_public_key()`][ExtendedPrivKey::ed25519_public_key()]
+    /// for the key in its usual form.
+    ///
+    /// [ExtendedPrivKey::ed25519_public_key()]: ./struct.ExtendedPrivKey.html#method.ed25519_public_key
+    pub fn public_key(&self) -> [u8; 33] {
+        let mut public_key = [0u8; 33];
+
+        match self.curve {
+            Curve::Ed25519 => {
+                public_key[1..].copy_from_slice(&SigningKey::from_bytes(&self.private_key).verifying_key().to_bytes());
+            }
+            Curve::NistP256 => {
+                let scalar = p256_scalar(&self.private_key).expect("private key is always valid");
+                let point = (ProjectivePoint::GENERATOR * scalar).to_affine().to_encoded_point(true);
+
+                public_key.copy_from_slice(point.as_bytes());
+            }
+        }
+
+        public_key
+    }
+
+    /// Get the 32 byte ed25519 public key, or `None` for keys on other curves
+    pub fn ed25519_public_key(&self) -> Option<[u8; 32]> {
+        match self.curve {
+            Curve::Ed25519 => Some(SigningKey::from_bytes(&self.private_key).verifying_key().to_bytes()),
+            Curve::NistP256 => None,
+        }
+    }
+
+    /// Get the chain code
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Get the [`Curve`][Curve] of the key
+    ///
+    /// [Curve]: ./enum.Curve.html
+    pub fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    /// Get the number of derivation steps from the master key, which is at depth 0
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Get the fingerprint of the parent key, or zeroes for a master key
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// Get the [`ChildNumber`][ChildNumber] this key was derived with
+    ///
+    /// [ChildNumber]: ../bip32/enum.ChildNumber.html
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Get the first 4 bytes of the HASH160 of the public key, as used for `parent_fingerprint` by children
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let mut fingerprint = [0u8; 4];
+
+        fingerprint.copy_from_slice(&hash160(&self.public_key())[..4]);
+
+        fingerprint
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ExtendedPrivKey {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl fmt::Debug for ExtendedPrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtendedPrivKey")
+         .field("curve", &self.curve)
+         .field("depth", &self.depth)
+         .field("parent_fingerprint", &self.parent_fingerprint)
+         .field("child_number", &self.child_number)
+         .finish()
+    }
+}
+
+/// Parse 32 big endian bytes as a NIST P-256 scalar, returning `None` if they are not below the
+/// curve order
+fn p256_scalar(bytes: &[u8]) -> Option<Scalar> {
+    let mut repr = FieldBytes::default();
+
+    repr.copy_from_slice(bytes);
+
+    Option::from(Scalar::from_repr(repr))
+}
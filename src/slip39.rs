@@ -0,0 +1,854 @@
+//! [SLIP-0039][slip39-standard] Shamir's secret sharing of the entropy behind a [`Mnemonic`][Mnemonic]
+//!
+//! The master secret is encrypted with a passphrase and split into groups of [`Share`][Share]s,
+//! each encoded as a phrase from the SLIP-0039 word list of 1024 words. Recovering the master
+//! secret takes `member_threshold` shares from each of `group_threshold` different groups.
+//!
+//! SLIP-0039 shares are not compatible with BIP0039 phrases, but the recovered master secret is
+//! the same entropy that was split, so the original [`Mnemonic`][Mnemonic] can be rebuilt from it.
+//!
+//! Only available with the `slip39` feature, which is off by default.
+//!
+//! ```rust
+//! use bip39::{Mnemonic, MnemonicType, Language};
+//! use bip39::slip39;
+//!
+//! let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+//!
+//! // 2 of 3 groups are needed: a single share kept by the owner, 2-of-3 family members or 3-of-5 friends
+//! let groups = slip39::split(&mnemonic, "TREZOR", 2, &[(1, 1), (2, 3), (3, 5)], 0).unwrap();
+//!
+//! for share in groups[1].shares() {
+//!     println!("{}", share.phrase());
+//! }
+//!
+//! // the owner's share and two shares of family members
+//! let shares: Vec<_> = groups[0].shares().iter().chain(&groups[1].shares()[..2]).cloned().collect();
+//! let secret = slip39::combine(&shares, "TREZOR").unwrap();
+//!
+//! assert_eq!(Mnemonic::from_entropy(&secret, Language::English).unwrap().phrase(), mnemonic.phrase());
+//! ```
+//!
+//! [slip39-standard]: https://github.com/satoshilabs/slips/blob/master/slip-0039.md
+//! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+//! [Share]: ./struct.Share.html
+//!
+
+use crypto::{gen_random_bytes, hmac_sha256, os_rng, pbkdf2_sha256};
+use error::ErrorKind;
+use error::Error;
+use mnemonic::Mnemonic;
+use rand::{CryptoRng, RngCore};
+use std::fmt;
+use std::mem;
+use util::{BitWriter, Bits10, IterExt};
+use wordlists;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const RADIX_BITS: usize = 10;
+const HEADER_WORDS: usize = 4;
+const CHECKSUM_WORDS: usize = 3;
+const MIN_SECRET_BYTES: usize = 16;
+const MIN_SHARE_WORDS: usize = HEADER_WORDS + (MIN_SECRET_BYTES * 8).div_ceil(RADIX_BITS) + CHECKSUM_WORDS;
+const MAX_SHARE_COUNT: usize = 16;
+const MAX_ITERATION_EXPONENT: u8 = 15;
+
+const BASE_ITERATION_COUNT: usize = 10000;
+const ROUND_COUNT: u8 = 4;
+
+const DIGEST_BYTES: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+
+const CUSTOMIZATION: &[u8] = b"shamir";
+const CUSTOMIZATION_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+static WORDLIST: &[&str; 1024] = &wordlists::SLIP39;
+
+/// Exponent and logarithm tables of GF(256) with the Rijndael polynomial, generator 3
+static GF256: ([u8; 255], [u8; 256]) = gf256_tables();
+
+const fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+
+        // multiply by the generator 3 = x + 1
+        poly ^= poly << 1;
+
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+
+        i += 1;
+    }
+
+    (exp, log)
+}
+
+/// A single share of a master secret, as encoded in a SLIP-0039 phrase
+///
+/// Shares are created by [`split()`][split()], or parsed from a phrase with
+/// [`Share::from_phrase()`][Share::from_phrase()]. Shares with the same identifier can be
+/// recombined with [`combine()`][combine()].
+///
+/// With the `zeroize` feature (enabled by default) the phrase and share value are wiped from
+/// memory when dropped.
+///
+/// [split()]: ./fn.split.html
+/// [combine()]: ./fn.combine.html
+/// [Share::from_phrase()]: ./struct.Share.html#method.from_phrase
+#[derive(Clone)]
+pub struct Share {
+    phrase: String,
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Share {
+    /// Parse a [`Share`][Share] from its phrase
+    ///
+    /// The phrase supplied will be checked for word length, padding and the RS1024 checksum
+    /// specified in SLIP-0039. Words may be separated by any run of Unicode whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::slip39::Share;
+    ///
+    /// let phrase = "duckling enlarge academic academic agency result length solution fridge kidney \
+    ///               coal piece deal husband erode duke ajar critical decision keyboard";
+    /// let share = Share::from_phrase(phrase).unwrap();
+    ///
+    /// assert_eq!(share.group_threshold(), 1);
+    /// assert_eq!(share.member_threshold(), 1);
+    /// ```
+    ///
+    /// [Share]: ./struct.Share.html
+    pub fn from_phrase(phrase: &str) -> Result<Share, Error> {
+        let mut words = Vec::with_capacity(MIN_SHARE_WORDS);
+
+        for (position, word) in phrase.split_whitespace().enumerate() {
+            let index = WORDLIST.binary_search(&word).map_err(|_| ErrorKind::InvalidWord(position))?;
+
+            words.push(index as u16);
+        }
+
+        let share = Share::from_words(&words);
+
+        #[cfg(feature = "zeroize")]
+        words.zeroize();
+
+        share
+    }
+
+    fn from_words(words: &[u16]) -> Result<Share, Error> {
+        if words.len() < MIN_SHARE_WORDS {
+            Err(ErrorKind::InvalidWordLength(words.len()))?;
+        }
+
+        // The value is padded with zeroes to a whole number of words, but never by a whole byte
+        // or more, so the length of the secret is unambiguous.
+        let value_words = &words[HEADER_WORDS..words.len() - CHECKSUM_WORDS];
+        let padding = (value_words.len() * RADIX_BITS) % 16;
+
+        if padding > 8 {
+            Err(ErrorKind::InvalidWordLength(words.len()))?;
+        }
+
+        let extendable = (words[1] >> 4) & 1 == 1;
+
+        if rs1024_polymod(customization(extendable), words) != 1 {
+            Err(ErrorKind::InvalidChecksum)?;
+        }
+
+        let header = words[..HEADER_WORDS].iter().fold(0u64, |header, &word| (header << RADIX_BITS) | word as u64);
+
+        let group_threshold = ((header >> 12) & 0xF) as u8 + 1;
+        let group_count = ((header >> 8) & 0xF) as u8 + 1;
+
+        if group_threshold > group_count {
+            Err(ErrorKind::InvalidThreshold(group_threshold as usize, group_count as usize))?;
+        }
+
+        let first = value_words[0];
+
+        if first >> (RADIX_BITS - padding) != 0 {
+            Err(ErrorKind::InvalidSharePadding)?;
+        }
+
+        let mut value = vec![0; (value_words.len() * RADIX_BITS - padding).div_ceil(8)];
+        let mut bits = BitWriter::new(&mut value);
+
+        for i in (0..RADIX_BITS - padding).rev() {
+            bits.push((first >> i) & 1 == 1);
+        }
+
+        for &word in &value_words[1..] {
+            bits.push(Bits10::from(word));
+        }
+
+        bits.finish();
+
+        Ok(Share {
+            phrase: words_to_phrase(words),
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: ((header >> 20) & 0xF) as u8,
+            group_index: ((header >> 16) & 0xF) as u8,
+            group_threshold,
+            group_count,
+            member_index: ((header >> 4) & 0xF) as u8,
+            member_threshold: (header & 0xF) as u8 + 1,
+            value,
+        })
+    }
+
+    fn new(set: &ShareSet, group_index: u8, member_index: u8, member_threshold: u8, value: Vec<u8>) -> Share {
+        let header = (set.identifier as u64) << 25
+            | (set.extendable as u64) << 24
+            | (set.iteration_exponent as u64) << 20
+            | (group_index as u64) << 16
+            | ((set.group_threshold - 1) as u64) << 12
+            | ((set.group_count - 1) as u64) << 8
+            | (member_index as u64) << 4
+            | (member_threshold - 1) as u64;
+
+        let value_words = (value.len() * 8).div_ceil(RADIX_BITS);
+        let padding = value_words * RADIX_BITS - value.len() * 8;
+
+        let mut packed = vec![0; (value_words * RADIX_BITS).div_ceil(8)];
+        let mut bits = BitWriter::new(&mut packed);
+
+        for _ in 0..padding {
+            bits.push(false);
+        }
+
+        for byte in &value {
+            bits.push(byte);
+        }
+
+        bits.finish();
+        let mut words = Vec::with_capacity(HEADER_WORDS + value_words + CHECKSUM_WORDS);
+
+        words.extend((0..HEADER_WORDS).rev().map(|i| ((header >> (i * RADIX_BITS)) & 0x3FF) as u16));
+        words.extend(packed.iter().bits().map(|bits: Bits10| u16::from(bits)));
+        words.extend_from_slice(&[0; CHECKSUM_WORDS]);
+
+        let checksum = rs1024_polymod(customization(set.extendable), &words) ^ 1;
+        let len = words.len();
+
+        for (i, word) in words[len - CHECKSUM_WORDS..].iter_mut().enumerate() {
+            *word = ((checksum >> ((CHECKSUM_WORDS - 1 - i) * RADIX_BITS)) & 0x3FF) as u16;
+        }
+
+        let phrase = words_to_phrase(&words);
+
+        #[cfg(feature = "zeroize")]
+        {
+            packed.zeroize();
+            words.zeroize();
+        }
+
+        Share {
+            phrase,
+            identifier: set.identifier,
+            extendable: set.extendable,
+            iteration_exponent: set.iteration_exponent,
+            group_index,
+            group_threshold: set.group_threshold,
+            group_count: set.group_count,
+            member_index,
+            member_threshold,
+            value,
+        }
+    }
+
+    /// Get the share phrase as a string reference
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Get the random 15 bit identifier shared by all shares of the same master secret
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Whether the share uses the extendable backup format, in which the identifier is not part
+    /// of the encryption so new share sets can be created for the same encrypted master secret
+    pub fn extendable(&self) -> bool {
+        self.extendable
+    }
+
+    /// Get the iteration exponent, the encryption uses 10000 × 2^e PBKDF2 iterations
+    pub fn iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+
+    /// Get the index of the group this share belongs to, starting from 0
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// Get the number of groups needed to recover the master secret
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    /// Get the total number of groups
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    /// Get the index of this share within its group, starting from 0
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    /// Get the number of shares of this group needed to recover the group secret
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    fn same_set(&self, other: &Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Share {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.value.zeroize();
+    }
+}
+
+impl AsRef<str> for Share {
+    fn as_ref(&self) -> &str {
+        self.phrase()
+    }
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.phrase(), f)
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Share")
+         .field("identifier", &self.identifier)
+         .field("extendable", &self.extendable)
+         .field("iteration_exponent", &self.iteration_exponent)
+         .field("group_index", &self.group_index)
+         .field("group_threshold", &self.group_threshold)
+         .field("group_count", &self.group_count)
+         .field("member_index", &self.member_index)
+         .field("member_threshold", &self.member_threshold)
+         .finish()
+    }
+}
+
+/// The member [`Share`][Share]s of one group, as created by [`split()`][split()]
+///
+/// [Share]: ./struct.Share.html
+/// [split()]: ./fn.split.html
+#[derive(Clone, Debug)]
+pub struct ShareGroup {
+    group_index: u8,
+    member_threshold: u8,
+    shares: Vec<Share>,
+}
+
+impl ShareGroup {
+    /// Get the index of the group, starting from 0
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// Get the number of shares needed to recover the group secret
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    /// Get the shares of the group members
+    pub fn shares(&self) -> &[Share] {
+        &self.shares
+    }
+
+    /// Consume the `ShareGroup` and return its shares
+    pub fn into_shares(self) -> Vec<Share> {
+        self.shares
+    }
+}
+
+/// The parameters common to every share of a master secret
+struct ShareSet {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_threshold: u8,
+    group_count: u8,
+}
+
+/// Split the entropy of a [`Mnemonic`][Mnemonic] into groups of shares
+///
+/// See [`split_secret()`][split_secret()] for the meaning of the arguments.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [split_secret()]: ./fn.split_secret.html
+pub fn split(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    iteration_exponent: u8,
+) -> Result<Vec<ShareGroup>, Error> {
+    split_secret(mnemonic.entropy(), passphrase, group_threshold, groups, iteration_exponent)
+}
+
+/// Split the entropy of a [`Mnemonic`][Mnemonic] into groups of shares, with randomness from `rng`
+///
+/// See [`split_secret_with_rng()`][split_secret_with_rng()] for the meaning of the arguments.
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [split_secret_with_rng()]: ./fn.split_secret_with_rng.html
+pub fn split_with_rng<R>(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    iteration_exponent: u8,
+    rng: &mut R,
+) -> Result<Vec<ShareGroup>, Error>
+where
+    R: RngCore + CryptoRng + ?Sized,
+{
+    split_secret_with_rng(mnemonic.entropy(), passphrase, group_threshold, groups, iteration_exponent, rng)
+}
+
+/// Split a master secret into groups of shares
+///
+/// The master secret is encrypted with `passphrase`, which may only contain printable ASCII
+/// characters, using 10000 × 2^`iteration_exponent` PBKDF2 iterations. It then takes
+/// `group_threshold` of the `groups` to recover it, where each group is given as
+/// `(member_threshold, member_count)`.
+///
+/// The master secret must be at least 128 bits and a multiple of 16 bits long, otherwise an
+/// `Error` of kind `ErrorKind::InvalidMasterSecretLength` is returned. There can be at most 16
+/// groups of 16 members, and a member threshold of 1 is only allowed for a group with a single
+/// member, any other configuration returns an `Error` of kind `ErrorKind::InvalidThreshold`.
+///
+/// Shares are created in the extendable format. The identifier of the shares and the random
+/// coefficients of the splits come from the operating system RNG, an `Error` of kind
+/// `ErrorKind::RngFailure` is returned if it fails.
+pub fn split_secret(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    iteration_exponent: u8,
+) -> Result<Vec<ShareGroup>, Error> {
+    split_secret_with_rng(master_secret, passphrase, group_threshold, groups, iteration_exponent, &mut os_rng()?)
+}
+
+/// Split a master secret into groups of shares, with randomness from `rng`
+///
+/// Like [`split_secret()`][split_secret()], but the RNG can be an HSM or a hardware RNG, or a
+/// seeded one for reproducible shares in tests. Returns an `Error` of kind
+/// `ErrorKind::RngFailure` with the error of the RNG if it fails.
+///
+/// [split_secret()]: ./fn.split_secret.html
+pub fn split_secret_with_rng<R>(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    iteration_exponent: u8,
+    rng: &mut R,
+) -> Result<Vec<ShareGroup>, Error>
+where
+    R: RngCore + CryptoRng + ?Sized,
+{
+    if master_secret.len() < MIN_SECRET_BYTES || master_secret.len() % 2 != 0 {
+        Err(ErrorKind::InvalidMasterSecretLength(master_secret.len()))?;
+    }
+
+    check_passphrase(passphrase)?;
+
+    if iteration_exponent > MAX_ITERATION_EXPONENT {
+        Err(ErrorKind::InvalidIterationExponent(iteration_exponent))?;
+    }
+
+    if group_threshold == 0 || group_threshold as usize > groups.len() || groups.len() > MAX_SHARE_COUNT {
+        Err(ErrorKind::InvalidThreshold(group_threshold as usize, groups.len()))?;
+    }
+
+    for &(threshold, count) in groups {
+        if threshold == 0 || threshold > count || count as usize > MAX_SHARE_COUNT || (threshold == 1 && count > 1) {
+            Err(ErrorKind::InvalidThreshold(threshold as usize, count as usize))?;
+        }
+    }
+
+    let random = gen_random_bytes(rng, 2)?;
+
+    let set = ShareSet {
+        identifier: u16::from_be_bytes([random[0], random[1]]) & 0x7FFF,
+        extendable: true,
+        iteration_exponent,
+        group_threshold,
+        group_count: groups.len() as u8,
+    };
+
+    #[allow(unused_mut)]
+    let mut encrypted = feistel(master_secret, passphrase, &set, 0..ROUND_COUNT);
+    #[allow(unused_mut)]
+    let mut group_secrets = shamir_split(group_threshold, groups.len() as u8, &encrypted, rng)?;
+
+    let share_groups = groups.iter()
+                             .zip(&group_secrets)
+                             .map(|(&(threshold, count), (group_index, group_secret))| {
+                                 let shares = shamir_split(threshold, count, group_secret, rng)?
+                                     .into_iter()
+                                     .map(|(member_index, value)| Share::new(&set, *group_index, member_index, threshold, value))
+                                     .collect();
+
+                                 Ok(ShareGroup {
+                                     group_index: *group_index,
+                                     member_threshold: threshold,
+                                     shares,
+                                 })
+                             })
+                             .collect();
+
+    #[cfg(feature = "zeroize")]
+    {
+        encrypted.zeroize();
+
+        for (_, group_secret) in group_secrets.iter_mut() {
+            group_secret.zeroize();
+        }
+    }
+
+    share_groups
+}
+
+/// Recover the master secret from a set of shares
+///
+/// The shares may be given in any order, and may contain more shares than needed. Groups that
+/// don't have enough shares are ignored, as long as enough other groups are complete. The
+/// `passphrase` must be the one used when splitting the secret, a different passphrase is not
+/// detected and silently recovers a different master secret.
+///
+/// Returns an `Error` of kind `ErrorKind::MismatchedShares` if the shares belong to different
+/// sets, `ErrorKind::NotEnoughShares` if too few groups can be recovered, and
+/// `ErrorKind::InvalidShareDigest` if the recovered secret fails its integrity check.
+pub fn combine(shares: &[Share], passphrase: &str) -> Result<Vec<u8>, Error> {
+    check_passphrase(passphrase)?;
+
+    let first = shares.first().ok_or(ErrorKind::NotEnoughShares)?;
+
+    if shares.iter().any(|share| !first.same_set(share)) {
+        Err(ErrorKind::MismatchedShares)?;
+    }
+
+    let mut groups: Vec<Members> = Vec::new();
+
+    for share in shares {
+        let position = match groups.iter().position(|group| group.group_index == share.group_index) {
+            Some(position) => position,
+            None => {
+                groups.push(Members {
+                    group_index: share.group_index,
+                    member_threshold: share.member_threshold,
+                    values: Vec::new(),
+                });
+
+                groups.len() - 1
+            }
+        };
+
+        let group = &mut groups[position];
+
+        if group.member_threshold != share.member_threshold {
+            Err(ErrorKind::MismatchedShares)?;
+        }
+
+        match group.values.iter().find(|&&(member_index, _)| member_index == share.member_index) {
+            Some(&(_, value)) if value != &share.value[..] => Err(ErrorKind::MismatchedShares)?,
+            Some(_) => {}
+            None => group.values.push((share.member_index, &share.value)),
+        }
+    }
+
+    let mut group_secrets = Vec::with_capacity(first.group_threshold as usize);
+
+    for group in groups.iter().filter(|group| group.values.len() >= group.member_threshold as usize) {
+        if group_secrets.len() == first.group_threshold as usize {
+            break;
+        }
+
+        let values = &group.values[..group.member_threshold as usize];
+
+        group_secrets.push((group.group_index, shamir_recover(group.member_threshold, values)?));
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        Err(ErrorKind::NotEnoughShares)?;
+    }
+
+    let set = ShareSet {
+        identifier: first.identifier,
+        extendable: first.extendable,
+        iteration_exponent: first.iteration_exponent,
+        group_threshold: first.group_threshold,
+        group_count: first.group_count,
+    };
+
+    let values: Vec<_> = group_secrets.iter().map(|(index, secret)| (*index, &secret[..])).collect();
+
+    #[allow(unused_mut)]
+    let mut encrypted = shamir_recover(first.group_threshold, &values)?;
+    let master_secret = feistel(&encrypted, passphrase, &set, (0..ROUND_COUNT).rev());
+
+    #[cfg(feature = "zeroize")]
+    {
+        encrypted.zeroize();
+
+        for (_, group_secret) in group_secrets.iter_mut() {
+            group_secret.zeroize();
+        }
+    }
+
+    Ok(master_secret)
+}
+
+/// The distinct member shares given for one group
+struct Members<'a> {
+    group_index: u8,
+    member_threshold: u8,
+    values: Vec<(u8, &'a [u8])>,
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), Error> {
+    if passphrase.bytes().any(|byte| !(32..=126).contains(&byte)) {
+        Err(ErrorKind::InvalidPassphrase)?;
+    }
+
+    Ok(())
+}
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_EXTENDABLE
+    } else {
+        CUSTOMIZATION
+    }
+}
+
+fn words_to_phrase(words: &[u16]) -> String {
+    words.iter().map(|&word| WORDLIST[word as usize]).join(" ")
+}
+
+/// The RS1024 checksum, a Reed-Solomon code over GF(1024) that detects any error in up to 3 words
+fn rs1024_polymod(customization: &[u8], words: &[u16]) -> u32 {
+    const GEN: [u32; 10] = [
+        0x00E0_E040, 0x01C1_C080, 0x0383_8100, 0x0707_0200, 0x0E0E_0009,
+        0x1C0C_2412, 0x3808_6C24, 0x3090_FC48, 0x21B1_F890, 0x03F3_F120,
+    ];
+
+    let values = customization.iter().map(|&byte| byte as u32).chain(words.iter().map(|&word| word as u32));
+
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 20;
+
+        GEN.iter()
+           .enumerate()
+           .filter(|&(i, _)| (top >> i) & 1 == 1)
+           .fold(((checksum & 0xF_FFFF) << RADIX_BITS) ^ value, |checksum, (_, gen)| checksum ^ gen)
+    })
+}
+
+/// The 4 round Feistel network used to encrypt and decrypt the master secret, depending on the
+/// order of the `rounds`
+fn feistel<R>(input: &[u8], passphrase: &str, set: &ShareSet, rounds: R) -> Vec<u8>
+where
+    R: Iterator<Item = u8>,
+{
+    let half = input.len() / 2;
+    let iterations = (BASE_ITERATION_COUNT << set.iteration_exponent) / ROUND_COUNT as usize;
+
+    let mut left = input[..half].to_vec();
+    let mut right = input[half..].to_vec();
+    let mut round_key = vec![0u8; half];
+
+    // The salt is the customization string and identifier, unless the shares are extendable,
+    // followed by the right half
+    let mut salt = Vec::with_capacity(CUSTOMIZATION.len() + 2 + half);
+
+    if !set.extendable {
+        salt.extend_from_slice(CUSTOMIZATION);
+        salt.extend_from_slice(&set.identifier.to_be_bytes());
+    }
+
+    let prefix = salt.len();
+
+    // The password is the round number followed by the passphrase
+    let mut password = Vec::with_capacity(1 + passphrase.len());
+
+    password.push(0);
+    password.extend_from_slice(passphrase.as_bytes());
+
+    for round in rounds {
+        password[0] = round;
+        salt.truncate(prefix);
+        salt.extend_from_slice(&right);
+
+        pbkdf2_sha256(&password, &salt, iterations, &mut round_key);
+
+        for (byte, key) in left.iter_mut().zip(&round_key) {
+            *byte ^= key;
+        }
+
+        mem::swap(&mut left, &mut right);
+    }
+
+    let mut output = Vec::with_capacity(input.len());
+
+    output.extend_from_slice(&right);
+    output.extend_from_slice(&left);
+
+    #[cfg(feature = "zeroize")]
+    {
+        left.zeroize();
+        right.zeroize();
+        round_key.zeroize();
+        salt.zeroize();
+        password.zeroize();
+    }
+
+    output
+}
+
+/// Split `secret` into `count` shares, any `threshold` of which can recover it
+///
+/// Alongside the secret at x = 255, the polynomial passes through a digest of the secret at
+/// x = 254, which is used to check the recovered secret.
+fn shamir_split<R>(threshold: u8, count: u8, secret: &[u8], rng: &mut R) -> Result<Vec<(u8, Vec<u8>)>, Error>
+where
+    R: RngCore + CryptoRng + ?Sized,
+{
+    if threshold == 1 {
+        return Ok((0..count).map(|index| (index, secret.to_vec())).collect());
+    }
+
+    let random_count = threshold - 2;
+
+    let mut shares = Vec::with_capacity(count as usize);
+
+    for index in 0..random_count {
+        shares.push((index, gen_random_bytes(rng, secret.len())?));
+    }
+
+    #[allow(unused_mut)]
+    let mut random = gen_random_bytes(rng, secret.len() - DIGEST_BYTES)?;
+    let mut digest = Vec::with_capacity(secret.len());
+
+    digest.extend_from_slice(&hmac_sha256(&random, secret)[..DIGEST_BYTES]);
+    digest.extend_from_slice(&random);
+
+    let derived: Vec<_> = {
+        let mut base: Vec<_> = shares.iter().map(|(index, value)| (*index, &value[..])).collect();
+
+        base.push((DIGEST_INDEX, &digest));
+        base.push((SECRET_INDEX, secret));
+
+        (random_count..count).map(|index| (index, interpolate(&base, index))).collect()
+    };
+
+    shares.extend(derived);
+
+    #[cfg(feature = "zeroize")]
+    {
+        random.zeroize();
+        digest.zeroize();
+    }
+
+    Ok(shares)
+}
+
+/// Recover the secret from `threshold` distinct shares, checking it against its digest
+fn shamir_recover(threshold: u8, shares: &[(u8, &[u8])]) -> Result<Vec<u8>, Error> {
+    if threshold == 1 {
+        return Ok(shares[0].1.to_vec());
+    }
+
+    #[allow(unused_mut)]
+    let mut secret = interpolate(shares, SECRET_INDEX);
+    #[allow(unused_mut)]
+    let mut digest = interpolate(shares, DIGEST_INDEX);
+
+    let valid = hmac_sha256(&digest[DIGEST_BYTES..], &secret)[..DIGEST_BYTES] == digest[..DIGEST_BYTES];
+
+    #[cfg(feature = "zeroize")]
+    digest.zeroize();
+
+    if !valid {
+        #[cfg(feature = "zeroize")]
+        secret.zeroize();
+
+        Err(ErrorKind::InvalidShareDigest)?;
+    }
+
+    Ok(secret)
+}
+
+/// Evaluate the Lagrange polynomial through the distinct points `shares` at `x`, bytewise in GF(256)
+fn interpolate(shares: &[(u8, &[u8])], x: u8) -> Vec<u8> {
+    if let Some(&(_, value)) = shares.iter().find(|&&(index, _)| index == x) {
+        return value.to_vec();
+    }
+
+    let (ref exp, ref log) = GF256;
+
+    let log_product: u32 = shares.iter().map(|&(index, _)| log[(x ^ index) as usize] as u32).sum();
+
+    let mut result = vec![0u8; shares[0].1.len()];
+
+    for &(index, value) in shares {
+        let log_denominator: u32 = shares.iter()
+                                         .filter(|&&(other, _)| other != index)
+                                         .map(|&(other, _)| log[(index ^ other) as usize] as u32)
+                                         .sum();
+
+        // Every logarithm is below 255, so adding 255 for each share keeps this from underflowing
+        let log_basis = (log_product + 255 * shares.len() as u32 - log[(x ^ index) as usize] as u32 - log_denominator) % 255;
+
+        for (byte, &y) in result.iter_mut().zip(value) {
+            if y != 0 {
+                *byte ^= exp[((log[y as usize] as u32 + log_basis) % 255) as usize];
+            }
+        }
+    }
+
+    result
+}
@@ -1,4 +1,15 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+#[cfg(feature = "bip32")]
+use crypto::sha256d;
+
 pub(crate) trait IterExt: Iterator {
+    #[cfg(any(feature = "slip39", feature = "electrum"))]
     fn join<R>(&mut self, glue: &str) -> R
     where
         R: From<String>,
@@ -13,9 +24,11 @@ pub(crate) trait IterExt: Iterator {
 
         let mut buffer = String::with_capacity(lower * (10 + glue.len()));
 
+        reserve_secret(&mut buffer, first.as_ref().len());
         buffer.push_str(first.as_ref());
 
         for item in self {
+            reserve_secret(&mut buffer, glue.len() + item.as_ref().len());
             buffer.push_str(glue);
             buffer.push_str(item.as_ref());
         }
@@ -35,6 +48,26 @@ pub(crate) trait IterExt: Iterator {
 
 impl<I: Iterator> IterExt for I {}
 
+/// Make room for `additional` bytes in a buffer that may hold secret material
+///
+/// Unlike `String::reserve`, the old allocation is wiped when the buffer has to grow, rather than
+/// being left behind in freed memory.
+pub(crate) fn reserve_secret(buffer: &mut String, additional: usize) {
+    if buffer.capacity() - buffer.len() >= additional {
+        return;
+    }
+
+    let capacity = (buffer.len() + additional).max(buffer.capacity() * 2);
+    let mut grown = String::with_capacity(capacity);
+
+    grown.push_str(buffer);
+
+    #[cfg(feature = "zeroize")]
+    buffer.zeroize();
+
+    *buffer = grown;
+}
+
 pub(crate) trait Bits {
     const SIZE: usize;
 
@@ -49,7 +82,7 @@ impl Bits for u8 {
     }
 }
 
-impl<'a> Bits for &'a u8 {
+impl Bits for &u8 {
     const SIZE: usize = 8;
 
     fn bits(self) -> u32 {
@@ -80,24 +113,57 @@ impl From<Bits11> for u16 {
     }
 }
 
-pub(crate) struct BitWriter {
-    offset: usize,
-    remainder: u32,
-    inner: Vec<u8>,
+#[cfg(feature = "slip39")]
+impl Bits for bool {
+    const SIZE: usize = 1;
+
+    fn bits(self) -> u32 {
+        self as u32
+    }
 }
 
-impl BitWriter {
-    pub fn with_capacity(capacity: usize) -> Self {
-        let mut bytes = capacity / 8;
+#[cfg(feature = "slip39")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Bits10(u16);
+
+#[cfg(feature = "slip39")]
+impl Bits for Bits10 {
+    const SIZE: usize = 10;
 
-        if capacity % 8 != 0 {
-            bytes += 1;
-        }
+    fn bits(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+#[cfg(feature = "slip39")]
+impl From<u16> for Bits10 {
+    fn from(val: u16) -> Self {
+        Bits10(val)
+    }
+}
+
+#[cfg(feature = "slip39")]
+impl From<Bits10> for u16 {
+    fn from(val: Bits10) -> Self {
+        val.0
+    }
+}
+
+/// Packs bits into a buffer provided by the caller, most significant bit first
+pub(crate) struct BitWriter<'a> {
+    offset: usize,
+    remainder: u32,
+    written: usize,
+    inner: &'a mut [u8],
+}
 
+impl<'a> BitWriter<'a> {
+    pub fn new(inner: &'a mut [u8]) -> Self {
         Self {
             offset: 0,
             remainder: 0,
-            inner: Vec::with_capacity(bytes)
+            written: 0,
+            inner,
         }
     }
 
@@ -108,27 +174,37 @@ impl BitWriter {
         self.offset += B::SIZE;
 
         while self.offset >= 8 {
-            self.inner.push((self.remainder >> 24) as u8);
+            self.inner[self.written] = (self.remainder >> 24) as u8;
+            self.written += 1;
             self.remainder <<= 8;
             self.offset -= 8;
         }
     }
 
     pub fn len(&self) -> usize {
-        self.inner.len() * 8 + self.offset
+        self.written * 8 + self.offset
     }
 
-    pub fn into_bytes(mut self) -> Vec<u8> {
+    /// Write out the last, partial byte, and return the number of bytes written
+    pub fn finish(mut self) -> usize {
         if self.offset != 0 {
-            self.inner.push((self.remainder >> 24) as u8);
+            self.inner[self.written] = (self.remainder >> 24) as u8;
+            self.written += 1;
         }
 
-        self.inner
+        self.written
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BitWriter<'_> {
+    fn drop(&mut self) {
+        self.remainder.zeroize();
     }
 }
 
 pub(crate) struct BitIter<In: Bits, Out: Bits, I: Iterator<Item = In> + Sized> {
-    _phantom: ::std::marker::PhantomData<Out>,
+    _phantom: ::core::marker::PhantomData<Out>,
     source: I,
     read: usize,
     buffer: u64,
@@ -144,7 +220,7 @@ where
         let source = source.into_iter();
 
         BitIter {
-            _phantom: ::std::marker::PhantomData,
+            _phantom: ::core::marker::PhantomData,
             source,
             read: 0,
             buffer: 0,
@@ -183,9 +259,262 @@ where
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<In, Out, I> Drop for BitIter<In, Out, I>
+where
+    In: Bits,
+    Out: Bits,
+    I: Iterator<Item = In>,
+{
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
 /// Extract the first `bits` from the `source` byte
 pub(crate) fn checksum(source: u8, bits: u8) -> u8 {
     debug_assert!(bits <= 8, "Can operate on 8-bit integers only");
 
     source >> (8 - bits)
 }
+
+/// A phrase or password in Unicode NFKD form, see [`normalize_utf8()`](fn.normalize_utf8.html)
+///
+/// If a normalized copy had to be made, it is wiped on drop.
+pub(crate) struct Normalized<'a>(Cow<'a, str>);
+
+impl Deref for Normalized<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Normalized<'_> {
+    fn drop(&mut self) {
+        if let Cow::Owned(ref mut normalized) = self.0 {
+            normalized.zeroize();
+        }
+    }
+}
+
+/// Normalize a phrase or password to the Unicode NFKD form required by BIP0039
+///
+/// Most input is already normalized (all of the word lists are), in which case no allocation
+/// takes place.
+pub(crate) fn normalize_utf8(input: &str) -> Normalized<'_> {
+    if is_nfkd_quick(input.chars()) == IsNormalized::Yes {
+        return Normalized(Cow::Borrowed(input));
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+
+    for c in input.nfkd() {
+        reserve_secret(&mut normalized, c.len_utf8());
+        normalized.push(c);
+    }
+
+    Normalized(Cow::Owned(normalized))
+}
+
+/// The Damerau-Levenshtein distance between two words, in characters, where swapping two
+/// neighbouring characters counts as a single edit
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    weighted_edit_distance(a, b, 1, |x, y| (x != y) as usize)
+}
+
+/// Like `edit_distance`, but counting half edits: replacing a letter with one from a
+/// neighbouring key on a QWERTY keyboard costs 1, every other edit costs 2
+pub(crate) fn typo_distance(a: &str, b: &str) -> usize {
+    weighted_edit_distance(a, b, 2, |x, y| match (x == y, adjacent_keys(x, y)) {
+        (true, _) => 0,
+        (false, true) => 1,
+        (false, false) => 2,
+    })
+}
+
+fn weighted_edit_distance<F>(a: &str, b: &str, edit: usize, substitution: F) -> usize
+where
+    F: Fn(char, char) -> usize,
+{
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // three rows of the distance matrix, for the previous two characters of `a` and this one
+    let mut rows = [vec![0; b.len() + 1], vec![0; b.len() + 1], (0..=b.len()).map(|j| j * edit).collect::<Vec<usize>>()];
+
+    for i in 1..=a.len() {
+        rows.rotate_left(1);
+        rows[2][0] = i * edit;
+
+        for j in 1..=b.len() {
+            let cost = substitution(a[i - 1], b[j - 1]);
+            let mut distance = (rows[1][j] + edit).min(rows[2][j - 1] + edit).min(rows[1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[0][j - 2] + edit);
+            }
+
+            rows[2][j] = distance;
+        }
+    }
+
+    rows[2][b.len()]
+}
+
+/// Whether two lowercase letters are on neighbouring keys of a QWERTY keyboard
+fn adjacent_keys(a: char, b: char) -> bool {
+    const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+    let position = |c: char| ROWS.iter().enumerate().find_map(|(row, keys)| keys.find(c).map(|column| (row, column)));
+
+    match (position(a), position(b)) {
+        (Some(a), Some(b)) => {
+            let ((upper, u), (lower, l)) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+
+            // each row is shifted right by about half a key from the one above it
+            (upper == lower && (u == l + 1 || l == u + 1)) || (lower == upper + 1 && (u == l || u == l + 1))
+        }
+        _ => false,
+    }
+}
+
+/// Encode `data` followed by its 4 byte double SHA256 checksum as Base58
+#[cfg(feature = "bip32")]
+pub(crate) fn base58check_encode(data: &[u8]) -> String {
+    let mut buffer = Vec::with_capacity(data.len() + 4);
+
+    buffer.extend_from_slice(data);
+    buffer.extend_from_slice(&sha256d(data)[..4]);
+
+    let encoded = bs58::encode(&buffer).into_string();
+
+    #[cfg(feature = "zeroize")]
+    buffer.zeroize();
+
+    encoded
+}
+
+/// Decode a Base58Check string, returning `None` if it is malformed or the checksum doesn't match
+#[cfg(feature = "bip32")]
+pub(crate) fn base58check_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut buffer = bs58::decode(encoded).into_vec().ok()?;
+
+    if buffer.len() < 4 {
+        return None;
+    }
+
+    let data_len = buffer.len() - 4;
+
+    if sha256d(&buffer[..data_len])[..4] != buffer[data_len..] {
+        return None;
+    }
+
+    buffer.truncate(data_len);
+
+    Some(buffer)
+}
+
+/// Encode `data` as standard Base64, without padding
+#[cfg(feature = "bip85")]
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((data.len() * 4).div_ceil(3));
+
+    for chunk in data.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| group | (byte as u32) << (16 - 8 * i));
+
+        for i in 0..=chunk.len() {
+            encoded.push(ALPHABET[(group >> (18 - 6 * i)) as usize & 0x3F] as char);
+        }
+    }
+
+    encoded
+}
+
+/// Encode `data`, which must be a multiple of 4 bytes long, as Base85 with the RFC 1924 alphabet
+#[cfg(feature = "bip85")]
+pub(crate) fn base85_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 85] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+    debug_assert!(data.len() % 4 == 0, "Can encode whole 4 byte groups only");
+
+    let mut encoded = String::with_capacity(data.len() / 4 * 5);
+
+    for chunk in data.chunks(4) {
+        let group = chunk.iter().fold(0u32, |group, &byte| (group << 8) | byte as u32);
+
+        for divisor in &[85 * 85 * 85 * 85, 85 * 85 * 85, 85 * 85, 85, 1] {
+            encoded.push(ALPHABET[(group / divisor % 85) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(any(feature = "slip39", feature = "electrum"))]
+    #[test]
+    fn join_grows() {
+        let words = ["あいこくしん", "あおぞら", "われる", "ろんぶん"];
+        let joined: String = words.iter().join("\u{3000}");
+
+        assert_eq!(joined, "あいこくしん\u{3000}あおぞら\u{3000}われる\u{3000}ろんぶん");
+    }
+
+    #[test]
+    fn normalize_grows() {
+        assert_eq!(&*normalize_utf8("abandon"), "abandon");
+        assert_eq!(&*normalize_utf8("caf\u{e9}"), "cafe\u{301}");
+        assert_eq!(&*normalize_utf8("\u{334d}"), "\u{30e1}\u{30fc}\u{30c8}\u{30eb}");
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("abandon", "abandon"), 0);
+        assert_eq!(edit_distance("abandon", "abadnon"), 1);
+        assert_eq!(edit_distance("abandon", "abando"), 1);
+        assert_eq!(edit_distance("cat", "act"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "zoo"), 3);
+        assert_eq!(edit_distance("e\u{301}le\u{300}ve", "eleve"), 2);
+    }
+
+    #[test]
+    fn typo_distances() {
+        assert_eq!(typo_distance("abandon", "abandon"), 0);
+        // s and d are neighbours, n and p are not
+        assert_eq!(typo_distance("abandon", "abansop"), 3);
+        assert_eq!(typo_distance("kitchen", "kitcjen"), 1);
+        assert_eq!(typo_distance("kitchen", "kitcpen"), 2);
+        assert_eq!(typo_distance("kitchen", "kitchn"), 2);
+        assert_eq!(typo_distance("cat", "act"), 2);
+        assert!(adjacent_keys('w', 'a') && adjacent_keys('s', 'z') && adjacent_keys('p', 'o'));
+        assert!(!adjacent_keys('q', 's') && !adjacent_keys('a', 'l') && !adjacent_keys('a', '\u{e9}'));
+    }
+
+    #[cfg(feature = "bip85")]
+    #[test]
+    fn base64() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg");
+        assert_eq!(base64_encode(b"fo"), "Zm8");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg");
+        assert_eq!(base64_encode(&[0xfb, 0xff]), "+/8");
+    }
+
+    #[cfg(feature = "bip85")]
+    #[test]
+    fn base85() {
+        assert_eq!(base85_encode(b""), "");
+        assert_eq!(base85_encode(b"abcd"), "VPa!s");
+        assert_eq!(base85_encode(&[0xff; 4]), "|NsC0");
+    }
+}
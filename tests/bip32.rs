@@ -0,0 +1,283 @@
+#![cfg(feature = "bip32")]
+
+extern crate bip39;
+
+use ::bip39::{Mnemonic, Language, Seed};
+use ::bip39::bip32::{ChildNumber, DerivationPath, ExtendedPrivKey, ExtendedPubKey, Network};
+
+// Test vectors from https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vectors
+//
+// (seed, [(path, xpub, xprv)])
+const VECTOR_1: (&str, &[(&str, &str, &str)]) = (
+    "000102030405060708090a0b0c0d0e0f",
+    &[
+        (
+            "m",
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+        ),
+        (
+            "m/0'",
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+        ),
+        (
+            "m/0'/1",
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+            "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+        ),
+        (
+            "m/0'/1/2'",
+            "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+            "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+        ),
+        (
+            "m/0'/1/2'/2",
+            "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+            "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+        ),
+        (
+            "m/0'/1/2'/2/1000000000",
+            "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+            "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+        ),
+    ],
+);
+
+const VECTOR_2: (&str, &[(&str, &str, &str)]) = (
+    "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+    &[
+        (
+            "m",
+            "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB",
+            "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+        ),
+        (
+            "m/0",
+            "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH",
+            "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+        ),
+        (
+            "m/0/2147483647'",
+            "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a",
+            "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+        ),
+        (
+            "m/0/2147483647'/1",
+            "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon",
+            "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+        ),
+        (
+            "m/0/2147483647'/1/2147483646'",
+            "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL",
+            "xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc",
+        ),
+        (
+            "m/0/2147483647'/1/2147483646'/2",
+            "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt",
+            "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j",
+        ),
+    ],
+);
+
+// Retention of leading zeros
+const VECTOR_3: (&str, &[(&str, &str, &str)]) = (
+    "4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be",
+    &[
+        (
+            "m",
+            "xpub661MyMwAqRbcEZVB4dScxMAdx6d4nFc9nvyvH3v4gJL378CSRZiYmhRoP7mBy6gSPSCYk6SzXPTf3ND1cZAceL7SfJ1Z3GC8vBgp2epUt13",
+            "xprv9s21ZrQH143K25QhxbucbDDuQ4naNntJRi4KUfWT7xo4EKsHt2QJDu7KXp1A3u7Bi1j8ph3EGsZ9Xvz9dGuVrtHHs7pXeTzjuxBrCmmhgC6",
+        ),
+        (
+            "m/0'",
+            "xpub68NZiKmJWnxxS6aaHmn81bvJeTESw724CRDs6HbuccFQN9Ku14VQrADWgqbhhTHBaohPX4CjNLf9fq9MYo6oDaPPLPxSb7gwQN3ih19Zm4Y",
+            "xprv9uPDJpEQgRQfDcW7BkF7eTya6RPxXeJCqCJGHuCJ4GiRVLzkTXBAJMu2qaMWPrS7AANYqdq6vcBcBUdJCVVFceUvJFjaPdGZ2y9WACViL4L",
+        ),
+    ],
+);
+
+const VECTOR_4: (&str, &[(&str, &str, &str)]) = (
+    "3ddd5602285899a946114506157c7997e5444528f3003f6134712147db19b678",
+    &[
+        (
+            "m",
+            "xpub661MyMwAqRbcGczjuMoRm6dXaLDEhW1u34gKenbeYqAix21mdUKJyuyu5F1rzYGVxyL6tmgBUAEPrEz92mBXjByMRiJdba9wpnN37RLLAXa",
+            "xprv9s21ZrQH143K48vGoLGRPxgo2JNkJ3J3fqkirQC2zVdk5Dgd5w14S7fRDyHH4dWNHUgkvsvNDCkvAwcSHNAQwhwgNMgZhLtQC63zxwhQmRv",
+        ),
+        (
+            "m/0'",
+            "xpub69AUMk3qDBi3uW1sXgjCmVjJ2G6WQoYSnNHyzkmdCHEhSZ4tBok37xfFEqHd2AddP56Tqp4o56AePAgCjYdvpW2PU2jbUPFKsav5ut6Ch1m",
+            "xprv9vB7xEWwNp9kh1wQRfCCQMnZUEG21LpbR9NPCNN1dwhiZkjjeGRnaALmPXCX7SgjFTiCTT6bXes17boXtjq3xLpcDjzEuGLQBM5ohqkao9G",
+        ),
+        (
+            "m/0'/1'",
+            "xpub6BJA1jSqiukeaesWfxe6sNK9CCGaujFFSJLomWHprUL9DePQ4JDkM5d88n49sMGJxrhpjazuXYWdMf17C9T5XnxkopaeS7jGk1GyyVziaMt",
+            "xprv9xJocDuwtYCMNAo3Zw76WENQeAS6WGXQ55RCy7tDJ8oALr4FWkuVoHJeHVAcAqiZLE7Je3vZJHxspZdFHfnBEjHqU5hG1Jaj32dVoS6XLT1",
+        ),
+    ],
+);
+
+// Keys that must be rejected, modelled after test vector 5
+const INVALID_KEYS: &[&str] = &[
+    // pubkey version / prvkey mismatch
+    "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gYweD1YUMnzkxQw1bm6XhhCCXF5rvDu3SQRW2A1Z5yqnVwyY4cNT",
+    // prvkey version / pubkey mismatch
+    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChpzxM5bEu4ku6ynu4tP6GqJ5kziULDsCA7bVctSatEcmUDntDMZ",
+    // invalid pubkey prefix 04
+    "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ45ycVBsADt89FVXeDkYqbSeZmpjjnJETkyyiMwXokWPisrtUjm",
+    // invalid prvkey prefix 01
+    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChnSg6bmoEgzBeJUNzvQF35FWGXz67kJ9g4FkYqRw3duegVvnguE",
+    // zero depth with non-zero parent fingerprint
+    "xprv9s2SVEMYPrA5zFr9cMZoqCQE6996p9PcDSAJdygf2wXW35yPEq4R8WjZcNDGuQFXjzJuMEWuHjMBXPKa4QGPyjiiAZJYQvsRPTuqBWKvEZh",
+    // zero depth with non-zero index
+    "xprv9s21ZrQH143K5xHBs26cwZK5DysagCJvyKkvGxYZfF4mZAqjPTNZDYRPyzMWuZqh2Ah4465C1KR38McHpLVffLbyzqfTkrY5tYLVhTL5ye4",
+    // unknown extended key version
+    "pGoh3VSiBwoWmRoSExKdpxHJBCMF5iacGac3mc7Q7j3RD8AADSrpaVmfhA5z6Uz5ZG3GSCE4Cf5vdzqN9DRV5WhsZS6meEhwZQwcPbLbHumsKTty",
+    // private key 0
+    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChijLXZSun8bsGj49MuvWWsqL9fqS5fhiDUkRQvq8cj8L42RGwHP",
+    // private key n
+    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkg5hntwdZH6QYdrGVYWUCS2Xv6FCMHoYQZYQDohv67LnGTwiNd",
+    // invalid pubkey, not on the curve
+    "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gYym6yCVZtiQKSpLUqpuy2xafsZZR8vydJmD1kZ1yXu2LotCeeYJ",
+    // invalid checksum
+    "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHj",
+    // invalid length
+    "DeaWiRvhTUWHmRFa65QcRFoZqVNmvXCnyi7cod8wKuH6s3dLhoawqehRCwzNEK1fVrh3ojSNBkvrBj6GRe5UGW5qpMwtda7wfu3xHzJHBs1gum",
+];
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn test_vector(vector: (&str, &[(&str, &str, &str)])) {
+    let (seed, chain) = vector;
+    let master = ExtendedPrivKey::from_seed_bytes(&hex_to_bytes(seed), Network::Mainnet).unwrap();
+
+    for &(path, xpub, xprv) in chain {
+        let path: DerivationPath = path.parse().unwrap();
+        let key = master.derive_path(&path).unwrap();
+
+        assert_eq!(key.to_string(), xprv);
+        assert_eq!(key.to_extended_pub_key().to_string(), xpub);
+        assert_eq!(key.depth() as usize, path.as_ref().len());
+
+        let parsed_xprv: ExtendedPrivKey = xprv.parse().unwrap();
+        let parsed_xpub: ExtendedPubKey = xpub.parse().unwrap();
+
+        assert_eq!(parsed_xprv.to_string(), xprv);
+        assert_eq!(parsed_xpub.to_string(), xpub);
+        assert_eq!(parsed_xprv.private_key(), key.private_key());
+        assert_eq!(parsed_xpub, key.to_extended_pub_key());
+    }
+}
+
+#[test]
+fn vector_1() {
+    test_vector(VECTOR_1);
+}
+
+#[test]
+fn vector_2() {
+    test_vector(VECTOR_2);
+}
+
+#[test]
+fn vector_3() {
+    test_vector(VECTOR_3);
+}
+
+#[test]
+fn vector_4() {
+    test_vector(VECTOR_4);
+}
+
+#[test]
+fn invalid_keys() {
+    for key in INVALID_KEYS {
+        assert!(key.parse::<ExtendedPrivKey>().is_err(), "{} must be rejected", key);
+        assert!(key.parse::<ExtendedPubKey>().is_err(), "{} must be rejected", key);
+    }
+}
+
+#[test]
+fn master_from_seed() {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    let seed = Seed::new(&mnemonic, "TREZOR");
+
+    let master = ExtendedPrivKey::new_master(&seed, Network::Mainnet).unwrap();
+
+    assert_eq!(master.to_string(), "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF");
+
+    // The key stays out of debug output
+    assert!(!format!("{:?}", master).contains("xprv"));
+}
+
+#[test]
+fn testnet_serialization() {
+    let master = ExtendedPrivKey::from_seed_bytes(&hex_to_bytes("000102030405060708090a0b0c0d0e0f"), Network::Testnet).unwrap();
+    let key = master.derive_path(&"m/44'/1'/0'/0/0".parse().unwrap()).unwrap();
+
+    let tprv = "tprv8kBiDs5EwXgjE8t5m3AbfycqUUgXXcYUrpWL8yxv5bVTGRbNqYCqVwTirgXdqDD7DEvtYEDc2ZcUHc1L6ngs3UtdFBV1mr8T2wMtGaXWHPK";
+    let tpub = "tpubDGskNH7V5uNQ7busegqC5PGx3WCTgwjPS877RW1DVsHr6ur9Tw2RgS5b2qWVEKxFJNpmRk6ykPfnxATc2evWM96gfPGKkmft6qoeENeiZFw";
+
+    assert_eq!(key.to_string(), tprv);
+    assert_eq!(key.to_extended_pub_key().to_string(), tpub);
+    assert_eq!(tprv.parse::<ExtendedPrivKey>().unwrap().network(), Network::Testnet);
+    assert_eq!(tpub.parse::<ExtendedPubKey>().unwrap().network(), Network::Testnet);
+}
+
+#[test]
+fn public_derivation() {
+    let master = ExtendedPrivKey::from_seed_bytes(&hex_to_bytes(VECTOR_2.0), Network::Mainnet).unwrap();
+    let path: DerivationPath = "m/0/1/2/2000000000".parse().unwrap();
+
+    let from_private = master.derive_path(&path).unwrap().to_extended_pub_key();
+    let from_public = master.to_extended_pub_key().derive_path(&path).unwrap();
+
+    assert_eq!(from_private, from_public);
+}
+
+#[test]
+fn public_derivation_hardened() {
+    let master = ExtendedPrivKey::from_seed_bytes(&hex_to_bytes(VECTOR_1.0), Network::Mainnet).unwrap();
+
+    assert!(master.to_extended_pub_key().derive_child(ChildNumber::Hardened(0)).is_err());
+}
+
+#[test]
+fn invalid_child_number() {
+    let master = ExtendedPrivKey::from_seed_bytes(&hex_to_bytes(VECTOR_1.0), Network::Mainnet).unwrap();
+
+    assert!(master.derive_child(ChildNumber::Normal(1 << 31)).is_err());
+    assert!(master.derive_child(ChildNumber::Hardened(1 << 31)).is_err());
+}
+
+#[test]
+fn invalid_seed_length() {
+    assert!(ExtendedPrivKey::from_seed_bytes(&[0; 15], Network::Mainnet).is_err());
+    assert!(ExtendedPrivKey::from_seed_bytes(&[0; 65], Network::Mainnet).is_err());
+}
+
+#[test]
+fn derivation_path_parse() {
+    let path: DerivationPath = "m/44h/0H/0'/0/2147483647".parse().unwrap();
+
+    assert_eq!(path.as_ref(), &[
+        ChildNumber::Hardened(44),
+        ChildNumber::Hardened(0),
+        ChildNumber::Hardened(0),
+        ChildNumber::Normal(0),
+        ChildNumber::Normal(2147483647),
+    ]);
+    assert_eq!(path.to_string(), "m/44'/0'/0'/0/2147483647");
+    assert_eq!("m".parse::<DerivationPath>().unwrap(), DerivationPath::default());
+
+    for invalid in &["", "44'/0'", "m/", "m//0", "m/2147483648", "m/-1", "m/+1", "m/0''", "m/a", "M/0"] {
+        assert!(invalid.parse::<DerivationPath>().is_err(), "{} must be rejected", invalid);
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "bip85")]
+
+extern crate bip39;
+
+use ::bip39::{MnemonicType, Language};
+use ::bip39::bip85::RootKey;
+
+// Test vectors from https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+const ROOT: &str = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+
+// (path, derived entropy)
+const ENTROPY: &[(&str, &str)] = &[
+    (
+        "m/83696968'/0'/0'",
+        "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7",
+    ),
+    (
+        "m/83696968'/0'/1'",
+        "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e",
+    ),
+];
+
+// (mnemonic type, language, entropy, phrase)
+const MNEMONICS: &[(MnemonicType, Language, &str, &str)] = &[
+    (
+        MnemonicType::Words12,
+        Language::English,
+        "6250b68daf746d12a24d58b4787a714b",
+        "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+    ),
+    (
+        MnemonicType::Words18,
+        Language::English,
+        "938033ed8b12698449d4bbca3c853c66b293ea1b1ce9d9dc",
+        "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token",
+    ),
+    (
+        MnemonicType::Words24,
+        Language::English,
+        "ae131e2312cdc61331542efe0d1077bac5ea803adf24b313a4f0e48e9c51f37f",
+        "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+    ),
+    // Not part of the BIP0085 vectors, generated with an independent implementation
+    (
+        MnemonicType::Words15,
+        Language::English,
+        "04200996b62ec834559877e289ca38825ce360c0",
+        "aerobic able grant hobby uncle boss filter auction tip exact mixed again soda race absorb",
+    ),
+    #[cfg(feature = "japanese")]
+    (
+        MnemonicType::Words12,
+        Language::Japanese,
+        "2536954d9c7b38f2b3a70e8aab996381",
+        "おまいり\u{3000}にんてい\u{3000}こふん\u{3000}き\u{3099}んいろ\u{3000}にんい\u{3000}せ\u{3099}んこ\u{3099}\u{3000}ひめい\u{3000}まほう\u{3000}たたみ\u{3000}さとう\u{3000}さ\u{3099}いたく\u{3000}あてな",
+    ),
+    #[cfg(feature = "czech")]
+    (
+        MnemonicType::Words12,
+        Language::Czech,
+        "1a7661722ad4fb4c794f987d70947bc3",
+        "daleko rotmistr legie kroupa konina pozor vklad zajet obejmout odpor dohra okouzlit",
+    ),
+    #[cfg(feature = "portuguese")]
+    (
+        MnemonicType::Words12,
+        Language::Portuguese,
+        "d4d61e0b270e907205b394af1efbf2a0",
+        "rota ossada infrator diocese tedioso ciranda arroba gelo oposto veicular visto creche",
+    ),
+];
+
+fn root() -> RootKey {
+    RootKey::from_extended_key(ROOT.parse().unwrap())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+fn derive_entropy() {
+    for &(path, entropy) in ENTROPY {
+        assert_eq!(hex(&root().derive_entropy(&path.parse().unwrap()).unwrap()), entropy);
+    }
+}
+
+#[test]
+fn mnemonic() {
+    for &(mtype, lang, entropy, phrase) in MNEMONICS {
+        let mnemonic = root().mnemonic(mtype, lang, 0).unwrap();
+
+        assert_eq!(hex(mnemonic.entropy()), entropy);
+        assert_eq!(mnemonic.phrase(), phrase);
+    }
+}
+
+#[test]
+fn mnemonic_index() {
+    let first = root().mnemonic(MnemonicType::Words12, Language::English, 0).unwrap();
+    let second = root().mnemonic(MnemonicType::Words12, Language::English, 1).unwrap();
+
+    assert_ne!(first.phrase(), second.phrase());
+    assert!(root().mnemonic(MnemonicType::Words12, Language::English, 1 << 31).is_err());
+}
+
+#[test]
+fn hex_entropy() {
+    assert_eq!(
+        root().hex(64, 0).unwrap(),
+        "492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c"
+    );
+    assert_eq!(root().hex(16, 0).unwrap(), "3c678a761e24067fecc41c328a3d253d");
+
+    assert!(root().hex(15, 0).is_err());
+    assert!(root().hex(65, 0).is_err());
+}
+
+#[test]
+fn wif() {
+    assert_eq!(root().wif(0).unwrap(), "Kzyv4uF39d4Jrw2W7UryTHwZr1zQVNk4dAFyqE6BuMrMh1Za7uhp");
+}
+
+#[test]
+fn xprv() {
+    assert_eq!(
+        root().xprv(0).unwrap().to_string(),
+        "xprv9s21ZrQH143K2srSbCSg4m4kLvPMzcWydgmKEnMmoZUurYuBuYG46c6P71UGXMzmriLzCCBvKQWBUv3vPB3m1SATMhp3uEjXHJ42jFg7myX"
+    );
+}
+
+#[test]
+fn password_base64() {
+    assert_eq!(root().password_base64(21, 0).unwrap(), "dKLoepugzdVJvdL56ogNV");
+    assert_eq!(root().password_base64(86, 0).unwrap().len(), 86);
+
+    assert!(root().password_base64(19, 0).is_err());
+    assert!(root().password_base64(87, 0).is_err());
+}
+
+#[test]
+fn password_base85() {
+    assert_eq!(root().password_base85(12, 0).unwrap(), "_s`{TW89)i4`");
+    assert_eq!(root().password_base85(80, 0).unwrap().len(), 80);
+
+    assert!(root().password_base85(9, 0).is_err());
+    assert!(root().password_base85(81, 0).is_err());
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "electrum")]
+
+extern crate bip39;
+extern crate rand;
+
+use ::bip39::{CryptoRng, ErrorKind, Language, RngCore, RngError, RngErrorKind, Seed};
+use ::bip39::electrum::{ElectrumMnemonic, ElectrumSeedType, ElectrumV1, PhraseKind};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// (phrase, seed type, passphrase, seed)
+// The first vectors are from the Electrum test suite, the others were generated with an
+// independent implementation
+const VECTORS: &[(&str, ElectrumSeedType, &str, &str)] = &[
+    (
+        "wild father tree among universe such mobile favorite target dynamic credit identify",
+        ElectrumSeedType::Segwit,
+        "",
+        "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756",
+    ),
+    (
+        "wild father tree among universe such mobile favorite target dynamic credit identify",
+        ElectrumSeedType::Segwit,
+        "Did you ever hear the tragedy of Darth Plagueis the Wise?",
+        "4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f",
+    ),
+    (
+        "kiss live scene rude gate step hip quarter bunker oxygen motor glove",
+        ElectrumSeedType::TwoFactor,
+        "",
+        "225deba51fd87a88f74927f42096e233a4b498edb217cd29941545c5687594724489e2691d9ede6910626a2270f119ce596357fff3de31d559e3edbccd6f210a",
+    ),
+    (
+        "since sick check reward swamp mind board moral cross bounce mutual equip",
+        ElectrumSeedType::Standard,
+        "",
+        "7357e69320dcfe804c031a58d81314217bcd8ecec681ecc543be51b10e08b27a0e658424ce79e6f47f76d49ee5f8117ab8a11136e727fe475e27f0f56727bda3",
+    ),
+    (
+        "since sick check reward swamp mind board moral cross bounce mutual equip",
+        ElectrumSeedType::Standard,
+        "TREZOR",
+        "1bf4bc2c8d030c3eb7e884282951b017b75f38962a9f3bff1e035e60bb9ed4e66481bbface6b1ff9bbe90e0d287a7f3d7ae7634d817cf77673ee4c4be1ba3df4",
+    ),
+    (
+        "hungry sword tuna flat critic fiction ready until output dance profit remind",
+        ElectrumSeedType::TwoFactorSegwit,
+        "",
+        "e6d238fdd8c5158d69ed0551b1da0f299e320795bc2aa7980838592c2b48b5b27ebb95c8a1ae8ed1c07050da7c63d4b3fb77cd39c9f751e4b2902cd20ca79644",
+    ),
+    (
+        "exchange wonder picnic sort bulk coil strong abstract monitor arm culture panda",
+        ElectrumSeedType::Segwit,
+        "",
+        "8bd671cea3e30e164f25587d748720c6fc282d3e6495eac3f95f99e248fe24c316c3e70a8e7206fd42ccc5161b9d1d84bbfa28dc55345eeb953017a451f4d786",
+    ),
+];
+
+#[test]
+fn seeds() {
+    for &(phrase, seed_type, passphrase, expected) in VECTORS {
+        let mnemonic = ElectrumMnemonic::from_phrase(phrase).unwrap();
+        let seed = Seed::new_electrum(&mnemonic, passphrase);
+
+        assert_eq!(mnemonic.seed_type(), seed_type);
+        assert_eq!(ElectrumSeedType::for_phrase(phrase).unwrap(), seed_type);
+        assert_eq!(format!("{:x}", seed), expected);
+    }
+}
+
+#[test]
+fn normalization() {
+    let mnemonic = ElectrumMnemonic::from_phrase(" Wild FATHER\ttree among universe such mobile favorite target dynamic credit identify\n").unwrap();
+
+    assert_eq!(mnemonic.phrase(), VECTORS[0].0);
+
+    // Passphrases are normalized as well, accents and case don't matter
+    let upper = Seed::new_electrum(&mnemonic, "DID YOU EVER HEAR THE TRAGEDY OF DARTH PLAGUEIS THE WISE?");
+    let accented = Seed::new_electrum(&mnemonic, "Did you ever hear the tr\u{e1}gedy of Darth Plagueis the Wise?");
+
+    assert_eq!(format!("{:x}", upper), VECTORS[1].3);
+    assert_eq!(format!("{:x}", accented), VECTORS[1].3);
+}
+
+#[test]
+fn invalid_version() {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    assert!(ElectrumMnemonic::from_phrase(phrase).is_err());
+    assert!(ElectrumSeedType::for_phrase(phrase).is_err());
+    assert!(ElectrumMnemonic::validate(VECTORS[0].0, ElectrumSeedType::Segwit).is_ok());
+    assert!(ElectrumMnemonic::validate(VECTORS[0].0, ElectrumSeedType::Standard).is_err());
+}
+
+#[test]
+fn generate() {
+    for &seed_type in &[ElectrumSeedType::Standard, ElectrumSeedType::Segwit, ElectrumSeedType::TwoFactor, ElectrumSeedType::TwoFactorSegwit] {
+        let mnemonic = ElectrumMnemonic::new(seed_type);
+        let kind = PhraseKind::for_phrase(mnemonic.phrase(), Language::English);
+
+        assert_eq!(mnemonic.seed_type(), seed_type);
+        assert_eq!(mnemonic.phrase().split(' ').count(), 12);
+        assert_eq!(kind.electrum(), Some(seed_type));
+        assert!(!kind.is_ambiguous());
+    }
+}
+
+// An HSM that has gone away
+struct UnavailableRng;
+
+impl RngCore for UnavailableRng {
+    fn next_u32(&mut self) -> u32 {
+        panic!("HSM unavailable")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        panic!("HSM unavailable")
+    }
+
+    fn fill_bytes(&mut self, _: &mut [u8]) {
+        panic!("HSM unavailable")
+    }
+
+    fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), RngError> {
+        Err(RngError::new(RngErrorKind::Unavailable, "HSM unavailable"))
+    }
+}
+
+impl CryptoRng for UnavailableRng {}
+
+#[test]
+fn generate_with_rng() {
+    let mnemonic = ElectrumMnemonic::new_with_rng(ElectrumSeedType::Segwit, &mut StdRng::seed_from_u64(7));
+    let again = ElectrumMnemonic::try_new_with_rng(ElectrumSeedType::Segwit, &mut StdRng::seed_from_u64(7)).unwrap();
+
+    assert_eq!(mnemonic.phrase(), again.phrase());
+    assert_eq!(ElectrumMnemonic::try_new(ElectrumSeedType::Standard).unwrap().seed_type(), ElectrumSeedType::Standard);
+
+    match ElectrumMnemonic::try_new_with_rng(ElectrumSeedType::Segwit, &mut UnavailableRng).unwrap_err().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::RngFailure(ref error) => assert_eq!(error.kind, RngErrorKind::Unavailable),
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn detect() {
+    let bip39 = PhraseKind::for_phrase("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", Language::English);
+
+    assert!(bip39.is_bip39());
+    assert_eq!(bip39.electrum(), None);
+    assert!(!bip39.is_ambiguous());
+
+    let electrum = PhraseKind::for_phrase(VECTORS[0].0, Language::English);
+
+    assert!(!electrum.is_bip39());
+    assert_eq!(electrum.electrum(), Some(ElectrumSeedType::Segwit));
+
+    let both = PhraseKind::for_phrase("fix amount abandon abandon abandon abandon abandon abandon abandon abandon abandon accuse", Language::English);
+
+    assert!(both.is_bip39());
+    assert_eq!(both.electrum(), Some(ElectrumSeedType::Standard));
+    assert!(both.is_ambiguous());
+
+    let unknown = PhraseKind::for_phrase("not a mnemonic", Language::English);
+
+    assert!(unknown.is_unknown());
+}
+
+fn hex_decode(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+// From the Electrum test suite
+const V1_PHRASE: &str = "powerful random nobody notice nothing important anyway look away hidden message over";
+const V1_SEED: &str = "acb740e454c3134901d7c8f16497cc1c";
+
+#[test]
+fn v1_phrase() {
+    let v1 = ElectrumV1::from_phrase(V1_PHRASE).unwrap();
+
+    assert_eq!(v1.hex_seed(), V1_SEED);
+    assert_eq!(ElectrumV1::from_bytes(&hex_decode(V1_SEED)).unwrap().phrase(), V1_PHRASE);
+    assert_eq!(ElectrumV1::from_phrase(&V1_PHRASE.to_uppercase()).unwrap().as_bytes(), v1.as_bytes());
+
+    let stretched: String = v1.stretched_key().iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    assert_eq!(stretched, "21b880fda2fd30081834683a7049ac9e3941a42adbc3a4616c9a9275aa960c0d");
+
+    // Each word is the previous one plus the next base 1626 digit, 0xffffffff = 489 + 1626 * 807 + 1626^2 * 1624
+    let v1 = ElectrumV1::from_bytes(&hex_decode("00000000ffffffff0000065a8edad31a")).unwrap();
+
+    assert_eq!(v1.phrase(), "like like like fail husband howl like just just hardly point goal");
+
+    let random = ElectrumV1::new();
+
+    assert_eq!(ElectrumV1::from_phrase(&random.phrase()).unwrap().as_bytes(), random.as_bytes());
+}
+
+#[test]
+fn v1_generate_with_rng() {
+    let seed = ElectrumV1::new_with_rng(&mut StdRng::seed_from_u64(7));
+    let again = ElectrumV1::try_new_with_rng(&mut StdRng::seed_from_u64(7)).unwrap();
+
+    assert_eq!(seed.as_bytes(), again.as_bytes());
+    assert_eq!(ElectrumV1::try_new().unwrap().as_bytes().len(), 16);
+    assert!(ElectrumV1::try_new_with_rng(&mut UnavailableRng).is_err());
+}
+
+#[test]
+fn v1_invalid() {
+    assert!(ElectrumV1::validate("just love know never want time out there make look eye down").is_ok());
+    assert!(ElectrumV1::validate("just love know never want time out there make look eye").is_err());
+    assert!(ElectrumV1::validate("just love know never want time out there make look eye abandon").is_err());
+    // 1625 + 1626 * 1625 + 1626^2 * 1625 doesn't fit in 32 bits
+    assert!(ElectrumV1::validate("weary weapon unseen like like like like like like like like like").is_err());
+    assert!(ElectrumV1::from_bytes(&[0; 15]).is_err());
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn v1_master_public_key() {
+    let v1 = ElectrumV1::from_phrase(V1_PHRASE).unwrap();
+    let mpk: String = v1.master_public_key().unwrap().iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    assert_eq!(mpk, "e9d4b7866dd1e91c862aebf62a49548c7dbf7bcc6e4b7b8c9da820c7737968df9c09d5a3e271dc814a29981f81b3faaf2737b551ef5dcc6189cf0f8252c442b3");
+}
+
+#[test]
+fn detect_v1() {
+    let v1 = PhraseKind::for_phrase(V1_PHRASE, Language::English);
+
+    assert!(v1.is_electrum_v1());
+    assert!(!v1.is_bip39());
+    assert!(!v1.is_ambiguous());
+
+    let bip39 = PhraseKind::for_phrase("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", Language::English);
+
+    assert!(bip39.is_bip39());
+    assert!(!bip39.is_electrum_v1());
+    assert!(!PhraseKind::for_phrase(VECTORS[0].0, Language::English).is_electrum_v1());
+}
@@ -0,0 +1,200 @@
+extern crate bip39;
+extern crate rand;
+
+use ::bip39::{ErrorKind, Language, Mnemonic, MnemonicType};
+use ::bip39::entropy::{Contribution, Mixer, Source};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::{env, fs, process};
+
+fn physical_error(source: Source, input: &str) -> ErrorKind {
+    Mnemonic::from_physical(source, input, MnemonicType::Words12, Language::English).err().unwrap().downcast::<ErrorKind>().unwrap()
+}
+
+fn test_physical(source: Source, input: &str, mtype: MnemonicType, expected: &str) {
+    let mnemonic = Mnemonic::from_physical(source, input, mtype, Language::English).unwrap();
+
+    assert_eq!(source.needed(input, mtype).unwrap(), 0);
+    assert_eq!(format!("{:x}", mnemonic), expected);
+    assert_eq!(Mnemonic::from_phrase(mnemonic.phrase(), Language::English).unwrap().entropy(), mnemonic.entropy());
+}
+
+#[test]
+fn entropy_coins() {
+    test_physical(Source::CoinFlips, &"0000 1111\n".repeat(16), MnemonicType::Words12, &"0f".repeat(16));
+}
+
+#[test]
+fn entropy_dice() {
+    // 1 to 4 give two bits each, then 5 gives a 0 and 6 a 1
+    test_physical(Source::DiceRolls, &"1234 1234 1234 1234 56".repeat(4), MnemonicType::Words12, "1b1b1b1b46c6c6c6d1b1b1b1b46c6c6c");
+
+    let rolls = "251314446421414451643625131116514624615244523262431456126631636654562335454514264462356663146512543461413655546225212552453534365561465255241435525443431555534512625521531611141323";
+
+    test_physical(Source::DiceRolls, rolls, MnemonicType::Words24, "447fd33c7a8808ef0f99bc68f1b6b4d8dfd9e385dcca3a911d2e9391ce17dc05");
+}
+
+#[test]
+fn entropy_digits() {
+    let digits = "192541224824475771046563414839603062027868387380695604230411";
+
+    test_physical(Source::Digits, digits, MnemonicType::Words12, "35614a149efe44d73861f06320bb1bb0");
+}
+
+#[test]
+fn entropy_cards() {
+    let decks = "QC 5D 2S 4H AD 3S 6C 2D 8S 9H 7D 2C 9D KD KH TH JS 6S QD 3D JH 7S 8H 4S JC 8C TS 6D 5H 2H AS 6H 4C 7C JD 9C KC 8D QH 4D TD AH 9S AC 3C 5C TC 7H 3H QS KS 5S\n\
+                 qd 7s qh 4h 8d 8c 5h 4c 10h 6c ts 9d ad kd ac kc 6s 9c jc jd 8h 2s 3d as 7d qs 3s td 7h 5c";
+
+    test_physical(Source::Cards, decks, MnemonicType::Words24, "c89da14bc98d487735e7f4bb0a37cb932847d9e389c03c7bbc76f51608ff4102");
+
+    // A full deck isn't enough for 24 words
+    let deck = decks.lines().next().unwrap();
+
+    assert!(Source::Cards.needed(deck, MnemonicType::Words24).unwrap() > 0);
+
+    match Mnemonic::from_physical(Source::Cards, deck, MnemonicType::Words24, Language::English).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::NotEnoughEntropy(_, 256) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn entropy_needed() {
+    let mut rolls = String::new();
+
+    // Keep rolling sixes, which give one bit each, until enough
+    loop {
+        let needed = Source::DiceRolls.needed(&rolls, MnemonicType::Words12).unwrap();
+
+        if needed == 0 {
+            break;
+        }
+
+        rolls.push_str(&"6".repeat(needed));
+    }
+
+    assert_eq!(rolls.len(), 128);
+    assert_eq!(format!("{:x}", Mnemonic::from_physical(Source::DiceRolls, &rolls, MnemonicType::Words12, Language::English).unwrap()), "f".repeat(32));
+}
+
+#[test]
+fn entropy_invalid() {
+    match physical_error(Source::CoinFlips, "01 2") {
+        ErrorKind::InvalidEntropySymbol(2) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    match physical_error(Source::DiceRolls, "1234560") {
+        ErrorKind::InvalidEntropySymbol(6) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    match physical_error(Source::Digits, "0123456789a") {
+        ErrorKind::InvalidEntropySymbol(10) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    match physical_error(Source::Cards, "AS KD\n2C 1C") {
+        ErrorKind::InvalidEntropySymbol(3) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    // The same card can be drawn again from another deck, but not from the same one
+    match physical_error(Source::Cards, "AS KD\nAS 10d as") {
+        ErrorKind::DuplicateCard(4) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    match physical_error(Source::DiceRolls, "123456") {
+        ErrorKind::NotEnoughEntropy(10, 128) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn entropy_mixer() {
+    let mixer = |mtype| {
+        Mixer::new(mtype)
+            .rng(&mut StdRng::seed_from_u64(7)).unwrap()
+            .bytes(&[0xab; 31])
+            .physical(Source::DiceRolls, "1234").unwrap()
+            .physical(Source::CoinFlips, "101").unwrap()
+    };
+
+    assert_eq!(
+        mixer(MnemonicType::Words24).contributions(),
+        &[Contribution::Rng, Contribution::Bytes(31), Contribution::Physical(Source::DiceRolls, 8), Contribution::Physical(Source::CoinFlips, 3)][..]
+    );
+    assert_eq!(format!("{:x}", mixer(MnemonicType::Words24).mnemonic(Language::English).unwrap()), "fe676172ad570bbbb732fd500dc4b27e687d9bea80de0eecd80beeec9e5aeb88");
+
+    // Shorter mnemonics take the start of the same hash
+    let mnemonic = mixer(MnemonicType::Words12).mnemonic(Language::English).unwrap();
+
+    assert_eq!(format!("{:x}", mnemonic), "fe676172ad570bbbb732fd500dc4b27e");
+
+    // The system RNG makes every mnemonic different, and is enough on its own
+    let first = Mixer::new(MnemonicType::Words24).bytes(b"abc").system_rng().unwrap();
+
+    assert_eq!(first.contributions()[1], Contribution::SystemRng);
+    assert_ne!(first.mnemonic(Language::English).unwrap().entropy(), mnemonic.entropy());
+
+    match Mixer::new(MnemonicType::Words12).mnemonic(Language::English).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::NotEnoughEntropy(0, 128) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    assert!(Mixer::new(MnemonicType::Words12).physical(Source::DiceRolls, "1237").is_err());
+}
+
+#[test]
+fn entropy_mixer_not_enough() {
+    // 8 bits of dice and 3 of coins fall short of 256, bytes don't count
+    let mixer = Mixer::new(MnemonicType::Words24)
+        .bytes(b"abc")
+        .physical(Source::DiceRolls, "1234").unwrap()
+        .physical(Source::CoinFlips, "101").unwrap();
+
+    match mixer.mnemonic(Language::English).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::NotEnoughEntropy(11, 256) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    // Bytes anyone could know would otherwise make for a publicly known mnemonic
+    match Mixer::new(MnemonicType::Words12).bytes(&[0; 16]).mnemonic(Language::English).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::NotEnoughEntropy(0, 128) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    let flips = "01".repeat(64);
+
+    assert!(Mixer::new(MnemonicType::Words12).physical(Source::CoinFlips, &flips[1..]).unwrap().mnemonic(Language::English).is_err());
+    assert!(Mixer::new(MnemonicType::Words12).physical(Source::CoinFlips, &flips).unwrap().mnemonic(Language::English).is_ok());
+}
+
+#[test]
+fn entropy_mixer_file() {
+    let path = env::temp_dir().join(format!("bip39-hwrng-{}", process::id()));
+
+    fs::write(&path, b"abcdefghijklmnopqrstuvwxyz").unwrap();
+
+    let flips = "0".repeat(128);
+    let mixer = Mixer::new(MnemonicType::Words12).physical(Source::CoinFlips, &flips).unwrap().file(&path, 16);
+    let alone = Mixer::new(MnemonicType::Words12).file(&path, 16);
+    let short = Mixer::new(MnemonicType::Words12).file(&path, 27);
+
+    fs::remove_file(&path).unwrap();
+
+    let mixer = mixer.unwrap();
+
+    assert_eq!(mixer.contributions(), &[Contribution::Physical(Source::CoinFlips, 128), Contribution::File(path, 16)][..]);
+    assert!(short.is_err());
+
+    // A file counts for as many bits as were read from it
+    assert!(alone.unwrap().mnemonic(Language::English).is_ok());
+
+    // Files are kept apart from the same bytes passed in directly
+    let bytes = Mixer::new(MnemonicType::Words12).physical(Source::CoinFlips, &flips).unwrap().bytes(b"abcdefghijklmnop");
+
+    assert_ne!(mixer.mnemonic(Language::English).unwrap().entropy(), bytes.mnemonic(Language::English).unwrap().entropy());
+}
@@ -1,6 +1,9 @@
 extern crate bip39;
+extern crate rand;
 
-use ::bip39::{Mnemonic, MnemonicType, Language, Seed};
+use ::bip39::{CryptoRng, ErrorKind, Mnemonic, MnemonicType, Language, RngCore, RngError, RngErrorKind, Seed};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
 fn test_word_count(expected_word_count: usize) {
     let mnemonic_type = MnemonicType::for_word_count(expected_word_count).unwrap();
@@ -50,3 +53,51 @@ fn generate_from_invalid_entropy() {
 
     assert!(Mnemonic::from_entropy(entropy, Language::English).is_err());
 }
+
+#[test]
+fn generate_with_rng() {
+    let mnemonic = Mnemonic::new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(7));
+    let again = Mnemonic::new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(7));
+    let other = Mnemonic::new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(8));
+
+    assert_eq!(mnemonic.phrase().split(" ").count(), 12);
+    assert_eq!(mnemonic.phrase(), again.phrase());
+    assert_ne!(mnemonic.phrase(), other.phrase());
+}
+
+// An HSM that has gone away
+struct UnavailableRng;
+
+impl RngCore for UnavailableRng {
+    fn next_u32(&mut self) -> u32 {
+        panic!("HSM unavailable")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        panic!("HSM unavailable")
+    }
+
+    fn fill_bytes(&mut self, _: &mut [u8]) {
+        panic!("HSM unavailable")
+    }
+
+    fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), RngError> {
+        Err(RngError::new(RngErrorKind::Unavailable, "HSM unavailable"))
+    }
+}
+
+impl CryptoRng for UnavailableRng {}
+
+#[test]
+fn generate_fallible() {
+    assert_eq!(Mnemonic::try_new(MnemonicType::Words18, Language::English).unwrap().phrase().split(" ").count(), 18);
+
+    let seeded = Mnemonic::try_new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(7)).unwrap();
+
+    assert_eq!(seeded.phrase(), Mnemonic::new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(7)).phrase());
+
+    match Mnemonic::try_new_with_rng(MnemonicType::Words12, Language::English, &mut UnavailableRng).unwrap_err().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::RngFailure(ref error) => assert_eq!(error.kind, RngErrorKind::Unavailable),
+        error => panic!("unexpected error: {}", error),
+    }
+}
@@ -0,0 +1,85 @@
+extern crate bip39;
+extern crate unicode_normalization;
+
+use ::bip39::{Mnemonic, MnemonicType, Language, Seed};
+use unicode_normalization::UnicodeNormalization;
+
+fn normalize_language(lang: Language) {
+    for _ in 0..100 {
+        let mnemonic = Mnemonic::new(MnemonicType::Words24, lang);
+        let phrase = mnemonic.phrase();
+
+        let forms: [String; 3] = [
+            phrase.nfc().collect(),
+            phrase.nfd().collect(),
+            phrase.nfkc().collect(),
+        ];
+
+        for form in &forms {
+            assert!(Mnemonic::validate(form, lang).is_ok());
+
+            let parsed = Mnemonic::from_phrase(form.as_str(), lang).expect("Can create a Mnemonic");
+
+            assert_eq!(parsed.phrase(), phrase);
+            assert_eq!(parsed.entropy(), mnemonic.entropy());
+        }
+    }
+}
+
+#[test]
+fn normalize_english() {
+    normalize_language(Language::English);
+}
+
+#[cfg(feature = "chinese-simplified")]
+#[test]
+fn normalize_chinese_simplified() {
+    normalize_language(Language::ChineseSimplified);
+}
+
+#[cfg(feature = "chinese-traditional")]
+#[test]
+fn normalize_chinese_traditional() {
+    normalize_language(Language::ChineseTraditional);
+}
+
+#[cfg(feature = "french")]
+#[test]
+fn normalize_french() {
+    normalize_language(Language::French);
+}
+
+#[cfg(feature = "italian")]
+#[test]
+fn normalize_italian() {
+    normalize_language(Language::Italian);
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn normalize_japanese() {
+    normalize_language(Language::Japanese);
+}
+
+#[cfg(feature = "korean")]
+#[test]
+fn normalize_korean() {
+    normalize_language(Language::Korean);
+}
+
+#[cfg(feature = "spanish")]
+#[test]
+fn normalize_spanish() {
+    normalize_language(Language::Spanish);
+}
+
+#[test]
+fn normalize_password() {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+    let composed = Seed::new(&mnemonic, "caf\u{e9}");
+    let decomposed = Seed::new(&mnemonic, "cafe\u{301}");
+
+    assert_eq!(composed.as_bytes(), decomposed.as_bytes());
+}
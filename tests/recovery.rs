@@ -0,0 +1,301 @@
+extern crate bip39;
+
+use ::bip39::{Language, Mnemonic, MnemonicType};
+use ::bip39::recovery::{corrections, invalid_words, suggestions, CorrectionKind, Recovery, MAX_SUGGESTIONS};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+const PHRASE: &str = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+fn recover(phrase: &str, missing: usize) -> Vec<String> {
+    Recovery::new(phrase, Language::English)
+        .unwrap()
+        .missing_words(missing)
+        .candidates()
+        .unwrap()
+        .map(|mnemonic| mnemonic.phrase().to_string())
+        .collect()
+}
+
+fn assert_valid_and_unique(candidates: &[String]) {
+    let unique: HashSet<&String> = candidates.iter().collect();
+
+    assert_eq!(unique.len(), candidates.len(), "Candidates must be unique");
+
+    for candidate in candidates {
+        assert!(Mnemonic::validate(candidate, Language::English).is_ok());
+    }
+}
+
+#[test]
+fn unreadable_word() {
+    let candidates = recover("park remain person kitchen mule spell knee ? position rail grid ankle", 0);
+
+    assert!(candidates.iter().any(|candidate| candidate == PHRASE));
+    assert!(candidates.iter().all(|candidate| candidate.split(' ').filter(|word| *word == "armed").count() <= 1 || candidate == PHRASE));
+    assert_valid_and_unique(&candidates);
+
+    // One in 16 words passes a 4 bit checksum, give or take
+    assert!(candidates.len() > 64 && candidates.len() < 256, "{} candidates", candidates.len());
+}
+
+#[test]
+fn unreadable_last_word() {
+    let candidates = recover("park remain person kitchen mule spell knee armed position rail grid ?", 0);
+
+    // The last word is worked out from its entropy bits, 7 of them for 12 words
+    assert_eq!(candidates.len(), 128);
+    assert!(candidates.iter().any(|candidate| candidate == PHRASE));
+    assert_valid_and_unique(&candidates);
+}
+
+#[test]
+fn unreadable_words() {
+    let recovery = Recovery::new("park remain ? kitchen mule spell knee armed position rail grid ?", Language::English).unwrap();
+    let mut candidates = recovery.candidates().unwrap();
+
+    assert_eq!(candidates.progress().total(), 2048 * 128);
+    assert!(candidates.any(|mnemonic| mnemonic.phrase() == PHRASE));
+}
+
+#[test]
+fn missing_word() {
+    let candidates = recover("park remain person kitchen mule spell knee position rail grid ankle", 1);
+
+    assert!(candidates.iter().any(|candidate| candidate == PHRASE));
+    assert_valid_and_unique(&candidates);
+
+    // Inserting a word before or after the same word gives the same phrase only once
+    let abandon = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let candidates = recover(abandon, 1);
+
+    assert!(candidates.iter().any(|candidate| candidate == "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
+    assert_valid_and_unique(&candidates);
+}
+
+#[test]
+fn missing_word_and_placeholder() {
+    let candidates = Recovery::new("park remain person kitchen mule spell knee position rail grid ?", Language::English)
+        .unwrap()
+        .missing_words(1)
+        .candidates()
+        .unwrap();
+
+    // Any of the 12 positions for the missing word, and the last word from the checksum
+    assert_eq!(candidates.progress().total(), 12 * 2048 * 128);
+
+    let candidates: Vec<String> = candidates.take(1000).map(|mnemonic| mnemonic.phrase().to_string()).collect();
+
+    assert_eq!(candidates.len(), 1000);
+    assert_valid_and_unique(&candidates);
+}
+
+#[test]
+fn complete_phrase() {
+    assert_eq!(recover(PHRASE, 0), [PHRASE]);
+    assert!(recover("park remain person kitchen mule spell knee armed position rail grid armed", 0).is_empty());
+}
+
+#[test]
+fn matching() {
+    let candidates: Vec<Mnemonic> = Recovery::new("park remain person kitchen mule spell knee ? position rail grid ?", Language::English)
+        .unwrap()
+        .matching(|mnemonic| mnemonic.entropy()[0] == 0xa0)
+        .candidates()
+        .unwrap()
+        .take(10)
+        .collect();
+
+    assert_eq!(candidates.len(), 10);
+    assert!(candidates.iter().all(|mnemonic| mnemonic.entropy()[0] == 0xa0));
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn matching_fingerprint() {
+    use ::bip39::Seed;
+    use ::bip39::bip32::{ExtendedPrivKey, Network};
+
+    let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+    let master = ExtendedPrivKey::new_master(&Seed::new(&mnemonic, "TREZOR"), Network::Mainnet).unwrap();
+
+    let candidates: Vec<Mnemonic> = Recovery::new("park remain person kitchen mule spell knee armed position rail grid ?", Language::English)
+        .unwrap()
+        .matching_fingerprint(master.fingerprint(), "TREZOR")
+        .candidates()
+        .unwrap()
+        .collect();
+
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].phrase(), PHRASE);
+}
+
+#[test]
+fn progress() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = reports.clone();
+
+    let mut candidates = Recovery::new("park remain ? kitchen mule spell knee armed position ? grid ankle", Language::English)
+        .unwrap()
+        .on_progress(move |progress| recorded.lock().unwrap().push(progress))
+        .candidates()
+        .unwrap();
+
+    assert_eq!(candidates.progress().total(), 2048 * 2048);
+    assert_eq!(candidates.progress().tried(), 0);
+
+    candidates.by_ref().take(10).count();
+
+    let tried = candidates.progress().tried();
+
+    assert!(tried > 0 && tried < 2048 * 2048);
+
+    let reports = reports.lock().unwrap();
+
+    assert!(reports.windows(2).all(|pair| pair[0].tried() < pair[1].tried()));
+}
+
+#[test]
+fn progress_done() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = reports.clone();
+
+    let count = Recovery::new("park remain person kitchen mule spell knee ? position rail grid ankle", Language::English)
+        .unwrap()
+        .on_progress(move |progress| recorded.lock().unwrap().push(progress))
+        .candidates()
+        .unwrap()
+        .count();
+
+    let reports = reports.lock().unwrap();
+    let last = reports.last().unwrap();
+
+    assert!(count > 0);
+    assert_eq!(last.tried(), 2048);
+    assert_eq!(last.total(), 2048);
+}
+
+#[test]
+fn cancel() {
+    let cancel = Arc::new(AtomicBool::new(false));
+
+    let mut candidates = Recovery::new("park remain ? kitchen mule spell knee armed position ? grid ankle", Language::English)
+        .unwrap()
+        .cancel_flag(cancel.clone())
+        .candidates()
+        .unwrap();
+
+    assert!(candidates.next().is_some());
+
+    cancel.store(true, Ordering::Relaxed);
+
+    assert!(candidates.next().is_none());
+    assert!(candidates.progress().tried() < 2048 * 2048);
+}
+
+#[test]
+fn invalid() {
+    // 11 words can't be a phrase without a missing word
+    assert!(Recovery::new("park remain person kitchen mule spell knee position rail grid ankle", Language::English).unwrap().candidates().is_err());
+    assert!(Recovery::new("park remain person kitchen mule spell knee ? ? ? ? ?", Language::English).unwrap().candidates().is_err());
+    assert!(Recovery::new("park remain person kitchen mule spell knee bitcoin position rail grid ankle", Language::English).is_err());
+
+    let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English);
+    let phrase: Vec<&str> = mnemonic.words().collect();
+
+    assert!(Recovery::new(&phrase[..20].join(" "), Language::English).unwrap().missing_words(4).candidates().is_ok());
+    assert!(Recovery::new(&phrase[..19].join(" "), Language::English).unwrap().missing_words(5).candidates().is_err());
+}
+
+#[test]
+fn correct_misspelt_word() {
+    let corrections = corrections("park remain person kitchn mule spell knee armed position rail grid ankle", Language::English, 2).unwrap();
+
+    // Only known words can replace the misspelt one, and the closest one comes first
+    assert_eq!(corrections[0].mnemonic().phrase(), PHRASE);
+    assert_eq!(corrections[0].kind(), CorrectionKind::Substitution { position: 3, distance: 1 });
+    assert!(corrections.iter().all(|correction| match correction.kind() {
+        CorrectionKind::Substitution { position, .. } => position == 3,
+        CorrectionKind::Swap { .. } => false,
+    }));
+}
+
+#[test]
+fn correct_wrong_word() {
+    let phrase = "park remain person kitten mule spell knee armed position rail grid ankle";
+
+    assert!(Mnemonic::validate(phrase, Language::English).is_err());
+
+    let corrections = corrections(phrase, Language::English, 2).unwrap();
+    let kinds: Vec<CorrectionKind> = corrections.iter().map(|correction| correction.kind()).collect();
+
+    assert!(corrections.iter().any(|correction| correction.mnemonic().phrase() == PHRASE
+        && correction.kind() == CorrectionKind::Substitution { position: 3, distance: 2 }));
+    assert!(corrections.iter().all(|correction| Mnemonic::validate(&correction.mnemonic().phrase(), Language::English).is_ok()));
+
+    // Ranked by distance
+    assert!(kinds.windows(2).all(|pair| match (pair[0], pair[1]) {
+        (CorrectionKind::Substitution { distance: a, .. }, CorrectionKind::Substitution { distance: b, .. }) => a <= b,
+        (CorrectionKind::Swap { .. }, CorrectionKind::Substitution { distance, .. }) => distance > 1,
+        _ => true,
+    }));
+}
+
+#[test]
+fn correct_swapped_words() {
+    let phrase = "park remain person kitchen mule spell knee armed rail position grid ankle";
+
+    assert!(Mnemonic::validate(phrase, Language::English).is_err());
+
+    let corrections = corrections(phrase, Language::English, 0).unwrap();
+
+    assert!(corrections.iter().any(|correction| correction.mnemonic().phrase() == PHRASE
+        && correction.kind() == CorrectionKind::Swap { position: 8 }));
+}
+
+#[test]
+fn correct_invalid() {
+    assert!(corrections("park remain person kitchn mule spell knee armd position rail grid ankle", Language::English, 2).unwrap().is_empty());
+    assert!(corrections("park remain person kitchen mule spell knee armed position rail grid", Language::English, 2).is_err());
+}
+
+#[test]
+fn invalid_words_with_suggestions() {
+    let invalid = invalid_words("park remain persn kitchen mule spell knee ? position rail gird bitcoin", Language::English);
+    let indices: Vec<usize> = invalid.iter().map(|word| word.index()).collect();
+
+    // Placeholders are reported as well, they aren't words
+    assert_eq!(indices, [2, 7, 10, 11]);
+    assert_eq!(invalid[0].word(), "persn");
+    assert_eq!(invalid[0].suggestions()[0], "person");
+    assert!(invalid[1].suggestions().is_empty());
+    // g and b are neighbours, so "bird" is a closer typo than the swapped letters of "grid"
+    assert_eq!(invalid[2].suggestions()[0], "bird");
+    assert!(invalid[2].suggestions().contains(&"grid"));
+    assert!(invalid.iter().all(|word| word.suggestions().len() <= MAX_SUGGESTIONS));
+
+    assert!(invalid_words(PHRASE, Language::English).is_empty());
+}
+
+#[test]
+fn suggestions_ranking() {
+    // j is next to h on the keyboard, p isn't
+    assert_eq!(suggestions("kitcjen", Language::English, 1), ["kitchen"]);
+    assert_eq!(suggestions("KITCHEN", Language::English, 1), ["kitchen"]);
+    assert_eq!(suggestions("zzzzzzzz", Language::English, 5), Vec::<&str>::new());
+
+    let ranked = suggestions("grid", Language::English, 10);
+
+    assert_eq!(ranked[0], "grid");
+    assert!(ranked.len() > 1);
+}
+
+#[cfg(feature = "french")]
+#[test]
+fn suggestions_accents() {
+    // Missing accents are the closest match, ahead of words that only differ by a letter
+    let suggested = suggestions("eleve", Language::French, 3);
+
+    assert_eq!(suggested[0], "e\u{301}le\u{300}ve");
+}
@@ -0,0 +1,238 @@
+#![cfg(feature = "slip39")]
+
+extern crate bip39;
+extern crate rand;
+
+use ::bip39::{CryptoRng, ErrorKind, Mnemonic, MnemonicType, Language, RngCore, RngError, RngErrorKind};
+use ::bip39::slip39::{self, Share};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// Test vectors from https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json,
+// all using the passphrase "TREZOR"
+//
+// (shares, master secret)
+const VALID: &[(&[&str], &str)] = &[
+    (
+        &["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard"],
+        "bb54aac4b89dc868ba37d9cc21b2cece",
+    ),
+    (
+        &[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ],
+        "b43ceb7e57a0ea8766221624d01b0864",
+    ),
+    (
+        &["theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck"],
+        "989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92",
+    ),
+    (
+        &[
+            "humidity disease academic always aluminum jewelry energy woman receiver strategy amuse duckling lying evidence network walnut tactics forget hairy rebound impulse brother survive clothes stadium mailman rival ocean reward venture always armed unwrap",
+            "humidity disease academic agency actress jacket gross physics cylinder solution fake mortgage benefit public busy prepare sharp friar change work slow purchase ruler again tricycle involve viral wireless mixture anatomy desert cargo upgrade",
+        ],
+        "c938b319067687e990e05e0da0ecce1278f75ff58d9853f19dcaeed5de104aae",
+    ),
+    (
+        &["testify swimming academic academic column loyalty smear include exotic bedroom exotic wrist lobe cover grief golden smart junior estimate learn"],
+        "1679b4516e0ee5954351d288a838f45e",
+    ),
+];
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn parse(phrases: &[&str]) -> Vec<Share> {
+    phrases.iter().map(|phrase| Share::from_phrase(phrase).unwrap()).collect()
+}
+
+#[test]
+fn vectors() {
+    for &(phrases, master_secret) in VALID {
+        let shares = parse(phrases);
+
+        assert_eq!(hex(&slip39::combine(&shares, "TREZOR").unwrap()), master_secret);
+
+        for (share, phrase) in shares.iter().zip(phrases) {
+            assert_eq!(share.phrase(), *phrase);
+        }
+    }
+}
+
+#[test]
+fn share_fields() {
+    let (phrases, _) = VALID[1];
+    let share = Share::from_phrase(phrases[0]).unwrap();
+
+    assert!(!share.extendable());
+    assert_eq!(share.iteration_exponent(), 2);
+    assert_eq!(share.group_index(), 0);
+    assert_eq!(share.group_threshold(), 1);
+    assert_eq!(share.group_count(), 1);
+    assert_eq!(share.member_index(), 2);
+    assert_eq!(share.member_threshold(), 2);
+    assert_eq!(share.identifier(), Share::from_phrase(phrases[1]).unwrap().identifier());
+
+    assert!(Share::from_phrase(VALID[4].0[0]).unwrap().extendable());
+}
+
+#[test]
+fn invalid_checksum() {
+    let phrase = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney";
+
+    assert!(Share::from_phrase(phrase).is_err());
+}
+
+#[test]
+fn invalid_padding() {
+    let phrase = "duckling enlarge academic academic email result length solution fridge kidney coal piece deal husband erode duke ajar music cargo fitness";
+
+    assert!(Share::from_phrase(phrase).is_err());
+}
+
+#[test]
+fn invalid_word() {
+    let phrase = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision abandon";
+
+    assert!(Share::from_phrase(phrase).is_err());
+}
+
+#[test]
+fn invalid_length() {
+    let phrase = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision";
+
+    assert!(Share::from_phrase(phrase).is_err());
+}
+
+#[test]
+fn not_enough_shares() {
+    let (phrases, _) = VALID[1];
+
+    assert!(slip39::combine(&parse(&phrases[..1]), "TREZOR").is_err());
+    assert!(slip39::combine(&[], "TREZOR").is_err());
+}
+
+#[test]
+fn mismatched_shares() {
+    let mut shares = parse(VALID[1].0);
+
+    shares.extend(parse(VALID[0].0));
+
+    assert!(slip39::combine(&shares, "TREZOR").is_err());
+}
+
+#[test]
+fn wrong_passphrase() {
+    let (phrases, master_secret) = VALID[1];
+    let recovered = slip39::combine(&parse(phrases), "").unwrap();
+
+    assert_ne!(hex(&recovered), master_secret);
+}
+
+#[test]
+fn split_and_combine() {
+    let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English);
+    let groups = slip39::split(&mnemonic, "TREZOR", 2, &[(1, 1), (2, 3), (3, 5)], 0).unwrap();
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[2].member_threshold(), 3);
+    assert_eq!(groups[2].shares().len(), 5);
+
+    for group in &groups {
+        for share in group.shares() {
+            let parsed = Share::from_phrase(share.phrase()).unwrap();
+
+            assert!(parsed.extendable());
+            assert_eq!(parsed.phrase(), share.phrase());
+            assert_eq!(parsed.group_index(), group.group_index());
+        }
+    }
+
+    let family = groups[1].shares();
+    let friends = groups[2].shares();
+
+    // any two complete groups
+    let owner_and_family: Vec<_> = groups[0].shares().iter().chain(&family[1..]).cloned().collect();
+    let family_and_friends: Vec<_> = family[..2].iter().chain(&friends[2..]).cloned().collect();
+
+    for shares in &[owner_and_family, family_and_friends] {
+        assert_eq!(slip39::combine(shares, "TREZOR").unwrap(), mnemonic.entropy());
+    }
+
+    // an incomplete group is ignored, but doesn't make up for a missing one
+    let incomplete: Vec<_> = groups[0].shares().iter().chain(&family[..1]).chain(&friends[..3]).cloned().collect();
+    let missing: Vec<_> = groups[0].shares().iter().chain(&family[..1]).chain(&friends[..2]).cloned().collect();
+
+    assert_eq!(slip39::combine(&incomplete, "TREZOR").unwrap(), mnemonic.entropy());
+    assert!(slip39::combine(&missing, "TREZOR").is_err());
+}
+
+#[test]
+fn split_secret_lengths() {
+    for length in &[16, 18, 32, 64] {
+        let secret: Vec<u8> = (0..*length as u8).collect();
+        let groups = slip39::split_secret(&secret, "", 1, &[(2, 3)], 0).unwrap();
+
+        assert_eq!(slip39::combine(&groups[0].shares()[1..], "").unwrap(), secret);
+    }
+
+    assert!(slip39::split_secret(&[0; 15], "", 1, &[(1, 1)], 0).is_err());
+    assert!(slip39::split_secret(&[0; 17], "", 1, &[(1, 1)], 0).is_err());
+}
+
+#[test]
+fn split_invalid_parameters() {
+    let secret = [0u8; 16];
+
+    assert!(slip39::split_secret(&secret, "", 0, &[(1, 1)], 0).is_err());
+    assert!(slip39::split_secret(&secret, "", 2, &[(1, 1)], 0).is_err());
+    assert!(slip39::split_secret(&secret, "", 1, &[(3, 2)], 0).is_err());
+    assert!(slip39::split_secret(&secret, "", 1, &[(1, 2)], 0).is_err());
+    assert!(slip39::split_secret(&secret, "", 1, &[(2, 17)], 0).is_err());
+    assert!(slip39::split_secret(&secret, "", 1, &[(1, 1)], 16).is_err());
+    assert!(slip39::split_secret(&secret, "p\u{e4}ssword", 1, &[(1, 1)], 0).is_err());
+}
+
+// An HSM that has gone away
+struct UnavailableRng;
+
+impl RngCore for UnavailableRng {
+    fn next_u32(&mut self) -> u32 {
+        panic!("HSM unavailable")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        panic!("HSM unavailable")
+    }
+
+    fn fill_bytes(&mut self, _: &mut [u8]) {
+        panic!("HSM unavailable")
+    }
+
+    fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), RngError> {
+        Err(RngError::new(RngErrorKind::Unavailable, "HSM unavailable"))
+    }
+}
+
+impl CryptoRng for UnavailableRng {}
+
+#[test]
+fn split_with_rng() {
+    let mnemonic = Mnemonic::new_with_rng(MnemonicType::Words12, Language::English, &mut StdRng::seed_from_u64(7));
+    let groups = slip39::split_with_rng(&mnemonic, "", 1, &[(2, 3)], 0, &mut StdRng::seed_from_u64(7)).unwrap();
+    let again = slip39::split_secret_with_rng(mnemonic.entropy(), "", 1, &[(2, 3)], 0, &mut StdRng::seed_from_u64(7)).unwrap();
+
+    for (share, other) in groups[0].shares().iter().zip(again[0].shares()) {
+        assert_eq!(share.phrase(), other.phrase());
+    }
+
+    assert_eq!(slip39::combine(&again[0].shares()[1..], "").unwrap(), mnemonic.entropy());
+
+    match slip39::split_with_rng(&mnemonic, "", 1, &[(2, 3)], 0, &mut UnavailableRng).unwrap_err().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::RngFailure(ref error) => assert_eq!(error.kind, RngErrorKind::Unavailable),
+        error => panic!("unexpected error: {}", error),
+    }
+}
@@ -1,6 +1,6 @@
 extern crate bip39;
 
-use ::bip39::{Mnemonic, MnemonicType, Language};
+use ::bip39::{ErrorKind, Mnemonic, MnemonicType, Language};
 
 fn validate_language(lang: Language) {
     let types = &[
@@ -19,6 +19,13 @@ fn validate_language(lang: Language) {
             assert_eq!(m1.entropy(), m2.entropy());
         }
     }
+
+    for _ in 0..100 {
+        let m1 = Mnemonic::new(MnemonicType::Words24, lang);
+        let m2 = Mnemonic::from_abbreviated_phrase(m1.abbreviated_phrase(), lang).expect("Can create a Mnemonic");
+
+        assert_eq!(m1.phrase(), m2.phrase());
+    }
 }
 
 #[test]
@@ -105,3 +112,37 @@ fn validate_korean() {
 fn validate_spanish() {
     validate_language(Language::Spanish);
 }
+
+#[test]
+fn validate_any_language() {
+    for &lang in Language::all() {
+        let m1 = Mnemonic::new(MnemonicType::Words24, lang);
+        let m2 = Mnemonic::from_phrase_any_language(m1.phrase()).expect("Can create a Mnemonic");
+
+        assert_eq!(m2.language(), lang);
+        assert_eq!(m1.entropy(), m2.entropy());
+    }
+
+    // A typo is reported for the language the phrase is closest to
+    let typo = Mnemonic::from_phrase_any_language("park remain person kitchen mule spell knee armed position rail grid ankel");
+
+    match typo.unwrap_err().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::InvalidWord(index) => assert_eq!(index, 11),
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[cfg(feature = "french")]
+#[test]
+fn validate_ambiguous_language() {
+    // Every word is in both the English and French word lists, and the checksum works out in both
+    let phrase = "abandon amateur angle animal aspect badge bicycle bonus brave canal amateur fragile";
+
+    assert!(Mnemonic::validate(phrase, Language::English).is_ok());
+    assert!(Mnemonic::validate(phrase, Language::French).is_ok());
+
+    match Mnemonic::from_phrase_any_language(phrase).unwrap_err().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::AmbiguousLanguage(languages) => assert_eq!(languages, [Language::English, Language::French]),
+        error => panic!("unexpected error: {}", error),
+    }
+}
@@ -0,0 +1,247 @@
+extern crate bip39;
+#[cfg(feature = "korean")]
+extern crate unicode_normalization;
+
+use ::bip39::{ErrorKind, Language, Mnemonic, MnemonicType, WordList};
+use ::bip39::recovery::Recovery;
+use std::{env, fs, process};
+#[cfg(feature = "korean")]
+use unicode_normalization::UnicodeNormalization;
+
+fn wordlist_language(lang: Language) {
+    let wordlist = lang.wordlist();
+
+    assert_eq!(wordlist.len(), 2048);
+    assert_eq!(wordlist.iter().len(), 2048);
+
+    for (i, word) in wordlist.iter().enumerate() {
+        assert_eq!(wordlist.index_of(word), Some(i as u16));
+        assert_eq!(wordlist.word(i as u16), Some(word));
+        assert!(wordlist.starting_with(word).any(|w| w == word));
+    }
+
+    let all: Vec<&str> = wordlist.starting_with("").collect();
+    let mut sorted: Vec<&str> = wordlist.iter().collect();
+
+    sorted.sort();
+
+    assert_eq!(all, sorted);
+
+    for word in wordlist.iter().step_by(7) {
+        let first = word.chars().next().unwrap().len_utf8();
+        let prefix = &word[..first];
+
+        let expected: Vec<&str> = sorted.iter().cloned().filter(|w| w.starts_with(prefix)).collect();
+        let matches: Vec<&str> = wordlist.starting_with(prefix).collect();
+
+        assert_eq!(matches, expected);
+        assert_eq!(wordlist.starting_with(prefix).len(), expected.len());
+    }
+
+    assert_eq!(wordlist.starting_with("bitcoin!").count(), 0);
+    assert_eq!(wordlist.word(2048), None);
+}
+
+#[test]
+fn wordlist_english() {
+    let wordlist = Language::English.wordlist();
+    let words: Vec<&str> = wordlist.starting_with("zo").collect();
+
+    assert_eq!(words, ["zone", "zoo"]);
+    assert_eq!(wordlist.starting_with("zo").next_back(), Some("zoo"));
+    assert_eq!(wordlist.index_of("abandon"), Some(0));
+    assert_eq!(wordlist.index_of("ABANDON"), None);
+
+    wordlist_language(Language::English);
+}
+
+#[cfg(feature = "chinese-simplified")]
+#[test]
+fn wordlist_chinese_simplified() {
+    let wordlist = Language::ChineseSimplified.wordlist();
+
+    // Every word is a single character
+    assert_eq!(wordlist.starting_with("的").collect::<Vec<_>>(), ["的"]);
+
+    wordlist_language(Language::ChineseSimplified);
+}
+
+#[cfg(feature = "chinese-traditional")]
+#[test]
+fn wordlist_chinese_traditional() {
+    wordlist_language(Language::ChineseTraditional);
+}
+
+#[cfg(feature = "french")]
+#[test]
+fn wordlist_french() {
+    // Composed accents are normalized when looking up a word
+    assert_eq!(Language::French.wordlist().index_of("\u{e9}l\u{e8}ve"), Language::French.wordlist().index_of("e\u{301}le\u{300}ve"));
+    assert!(Language::French.wordlist().index_of("e\u{301}le\u{300}ve").is_some());
+
+    // And when searching by prefix
+    assert!(Language::French.wordlist().starting_with("\u{e9}l\u{e8}").any(|word| word == "e\u{301}le\u{300}ve"));
+    assert_eq!(Language::French.wordlist().starting_with("\u{e9}l").len(), Language::French.wordlist().starting_with("e\u{301}l").len());
+
+    wordlist_language(Language::French);
+}
+
+#[cfg(feature = "italian")]
+#[test]
+fn wordlist_italian() {
+    wordlist_language(Language::Italian);
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn wordlist_japanese() {
+    let wordlist = Language::Japanese.wordlist();
+
+    assert!(wordlist.starting_with("あい").any(|word| word == "あいこくしん"));
+    assert_eq!(wordlist.index_of("あいこくしん"), Some(0));
+
+    wordlist_language(Language::Japanese);
+}
+
+#[cfg(feature = "korean")]
+#[test]
+fn wordlist_korean() {
+    let wordlist = Language::Korean.wordlist();
+
+    // A composed syllable, as typed on a keyboard, finds the words in their decomposed form
+    for word in wordlist.iter().step_by(7) {
+        let syllable: String = word.nfc().take(1).collect();
+
+        assert!(wordlist.starting_with(&syllable).any(|w| w == word));
+    }
+
+    wordlist_language(Language::Korean);
+}
+
+#[cfg(feature = "spanish")]
+#[test]
+fn wordlist_spanish() {
+    wordlist_language(Language::Spanish);
+}
+
+#[cfg(feature = "czech")]
+#[test]
+fn wordlist_czech() {
+    wordlist_language(Language::Czech);
+}
+
+#[cfg(feature = "portuguese")]
+#[test]
+fn wordlist_portuguese() {
+    wordlist_language(Language::Portuguese);
+}
+
+fn custom_words() -> Vec<String> {
+    (0..2048).map(|i| format!("w{:04}rd", i)).collect()
+}
+
+// Each test keeps its custom lists for the rest of the run
+fn leak(wordlist: WordList) -> &'static WordList {
+    Box::leak(Box::new(wordlist))
+}
+
+#[test]
+fn wordlist_custom() {
+    let words = custom_words();
+    let lang = Language::custom(leak(WordList::from_words(&words).unwrap()));
+
+    assert_eq!(lang, lang);
+    assert_eq!(lang, Language::custom(lang.wordlist()));
+    assert_ne!(lang, Language::custom(leak(WordList::from_words(&words).unwrap())));
+    assert_eq!(lang.wordlist().index_of("w2047rd"), Some(2047));
+
+    let mnemonic = Mnemonic::new(MnemonicType::Words24, lang);
+
+    assert_eq!(Mnemonic::from_phrase(mnemonic.phrase(), lang).unwrap().entropy(), mnemonic.entropy());
+    assert_eq!(Mnemonic::from_entropy(mnemonic.entropy(), Language::English).unwrap().to_language(lang).phrase(), mnemonic.phrase());
+    assert!(Mnemonic::validate(&mnemonic.phrase(), Language::English).is_err());
+
+    let recovery = Recovery::new("w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd w0000rd ?", lang).unwrap();
+
+    assert_eq!(recovery.candidates().unwrap().count(), 128);
+}
+
+#[test]
+fn wordlist_custom_invalid() {
+    let mut words = custom_words();
+
+    assert!(WordList::from_words(&words[1..]).is_err());
+
+    words[7] = "w0003rd".to_string();
+
+    match WordList::from_words(&words).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::DuplicateWord(3, 7) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    words[7] = "two words".to_string();
+
+    match WordList::from_words(&words).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::InvalidWordlistWord(7) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    // Longer than the longest Korean word
+    words[7] = "w".repeat(37);
+
+    match WordList::from_words(&words).err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::InvalidWordlistWord(7) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+
+    // "abstain" starts like "abstract"
+    let mut words: Vec<&str> = Language::English.wordlist().iter().collect();
+
+    words[8] = "abstain";
+
+    match WordList::from_words(&words).unwrap().require_unique_prefixes().err().unwrap().downcast::<ErrorKind>().unwrap() {
+        ErrorKind::DuplicatePrefix(7, 8) => {}
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn wordlist_custom_prefixes() {
+    let words: Vec<&str> = Language::English.wordlist().iter().collect();
+    let lang = Language::custom(leak(WordList::from_words(&words).unwrap().require_unique_prefixes().unwrap()));
+    let mnemonic = Mnemonic::new(MnemonicType::Words12, lang);
+
+    assert_eq!(Mnemonic::from_abbreviated_phrase(mnemonic.abbreviated_phrase(), lang).unwrap().phrase(), mnemonic.phrase());
+}
+
+#[test]
+fn wordlist_custom_file() {
+    let path = env::temp_dir().join(format!("bip39-wordlist-{}.txt", process::id()));
+    let mut text = custom_words().join("\r\n");
+
+    // Composed characters are normalized like in the built-in lists
+    text = text.replace("w0001rd", "\u{e9}t\u{e9}");
+    fs::write(&path, &text).unwrap();
+
+    let wordlist = WordList::from_file(&path);
+
+    fs::remove_file(&path).unwrap();
+
+    let wordlist = wordlist.unwrap();
+
+    assert_eq!(wordlist.iter().nth(1), Some("e\u{301}te\u{301}"));
+    assert_eq!(wordlist.index_of("\u{e9}t\u{e9}"), Some(1));
+    assert!(WordList::from_file(env::temp_dir().join("bip39-no-such-wordlist.txt")).is_err());
+}
+
+#[cfg(feature = "bip85")]
+#[test]
+fn wordlist_custom_bip85() {
+    use ::bip39::bip85::RootKey;
+
+    let xprv = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+    let root = RootKey::from_extended_key(xprv.parse().unwrap());
+    let lang = Language::custom(leak(WordList::from_words(custom_words()).unwrap()));
+
+    assert!(root.mnemonic(MnemonicType::Words12, lang, 0).is_err());
+}